@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+/// Combat skills a hunter learns as they level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Ability {
+    PowerStrike,
+    AimedShot,
+    SecondWind,
+    Taunt,
+    // Class signatures, known from level 1 and never learned by level.
+    ShieldBash,
+    Volley,
+    FireFlask,
+}
+
+impl Ability {
+    /// Abilities any hunter learns by leveling.
+    pub const ALL: [Ability; 4] = [
+        Ability::PowerStrike,
+        Ability::AimedShot,
+        Ability::SecondWind,
+        Ability::Taunt,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Ability::PowerStrike => "Power Strike",
+            Ability::AimedShot => "Aimed Shot",
+            Ability::SecondWind => "Second Wind",
+            Ability::Taunt => "Taunt",
+            Ability::ShieldBash => "Shield Bash",
+            Ability::Volley => "Volley",
+            Ability::FireFlask => "Fire Flask",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Ability::PowerStrike => "A heavy blow that rolls an extra damage die.",
+            Ability::AimedShot => "A precise strike rolled with advantage that leaves the target bleeding.",
+            Ability::SecondWind => "Catch your breath: heal a quarter of max HP and regenerate.",
+            Ability::Taunt => "Goad the enemy into wild, weakened swings.",
+            Ability::ShieldBash => "Slam your shield into the enemy, stunning it on a hit.",
+            Ability::Volley => "Loose two quick shots, each rolled separately.",
+            Ability::FireFlask => "Hurl a flask that sets the enemy burning. Never misses.",
+        }
+    }
+
+    /// Level at which the ability is learned.
+    pub fn level_required(&self) -> u32 {
+        match self {
+            Ability::PowerStrike => 2,
+            Ability::AimedShot => 3,
+            Ability::SecondWind => 4,
+            Ability::Taunt => 5,
+            Ability::ShieldBash | Ability::Volley | Ability::FireFlask => 1,
+        }
+    }
+
+    /// Stamina spent per use.
+    pub fn cost(&self) -> i32 {
+        match self {
+            Ability::PowerStrike => 3,
+            Ability::AimedShot => 2,
+            Ability::SecondWind => 4,
+            Ability::Taunt => 1,
+            Ability::ShieldBash => 3,
+            Ability::Volley => 3,
+            Ability::FireFlask => 2,
+        }
+    }
+
+    /// Turns before the ability can be used again.
+    pub fn cooldown(&self) -> u32 {
+        match self {
+            Ability::PowerStrike => 2,
+            Ability::AimedShot => 3,
+            Ability::SecondWind => 5,
+            Ability::Taunt => 4,
+            Ability::ShieldBash => 4,
+            Ability::Volley => 2,
+            Ability::FireFlask => 3,
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+
+use super::player::{Item, ItemType, StatBonus};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum AffixKind {
+    Prefix,
+    Suffix,
+}
+
+/// A rolled modifier on a generated item. Stored in full on the item so
+/// saves keep their stats even if the affix pool changes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Affix {
+    pub name: String,
+    pub kind: AffixKind,
+    pub bonus: StatBonus,
+}
+
+// ── Affix pool ───────────────────────────────────────────────────────
+
+/// Inclusive stat ranges an affix rolls within.
+struct AffixTemplate {
+    name: &'static str,
+    kind: AffixKind,
+    applies_to: &'static [ItemType],
+    attack: (i32, i32),
+    defense: (i32, i32),
+    max_hp: (i32, i32),
+    weight: u32,
+}
+
+const WEAPON: &[ItemType] = &[ItemType::Weapon];
+const ARMOR: &[ItemType] = &[ItemType::Armor];
+const GEAR: &[ItemType] = &[ItemType::Weapon, ItemType::Armor, ItemType::Trinket];
+const WORN: &[ItemType] = &[ItemType::Armor, ItemType::Trinket];
+
+const AFFIXES: &[AffixTemplate] = &[
+    // Prefixes
+    AffixTemplate {
+        name: "Keen",
+        kind: AffixKind::Prefix,
+        applies_to: WEAPON,
+        attack: (1, 2),
+        defense: (0, 0),
+        max_hp: (0, 0),
+        weight: 4,
+    },
+    AffixTemplate {
+        name: "Vicious",
+        kind: AffixKind::Prefix,
+        applies_to: WEAPON,
+        attack: (2, 4),
+        defense: (0, 0),
+        max_hp: (0, 0),
+        weight: 1,
+    },
+    AffixTemplate {
+        name: "Sturdy",
+        kind: AffixKind::Prefix,
+        applies_to: ARMOR,
+        attack: (0, 0),
+        defense: (1, 2),
+        max_hp: (0, 0),
+        weight: 4,
+    },
+    AffixTemplate {
+        name: "Reinforced",
+        kind: AffixKind::Prefix,
+        applies_to: ARMOR,
+        attack: (0, 0),
+        defense: (2, 3),
+        max_hp: (2, 4),
+        weight: 1,
+    },
+    AffixTemplate {
+        name: "Gleaming",
+        kind: AffixKind::Prefix,
+        applies_to: WORN,
+        attack: (0, 0),
+        defense: (0, 0),
+        max_hp: (3, 6),
+        weight: 3,
+    },
+    // Suffixes
+    AffixTemplate {
+        name: "of the Bear",
+        kind: AffixKind::Suffix,
+        applies_to: GEAR,
+        attack: (0, 0),
+        defense: (0, 0),
+        max_hp: (4, 8),
+        weight: 3,
+    },
+    AffixTemplate {
+        name: "of the Wolf",
+        kind: AffixKind::Suffix,
+        applies_to: WEAPON,
+        attack: (1, 1),
+        defense: (0, 0),
+        max_hp: (2, 4),
+        weight: 2,
+    },
+    AffixTemplate {
+        name: "of Warding",
+        kind: AffixKind::Suffix,
+        applies_to: WORN,
+        attack: (0, 0),
+        defense: (1, 2),
+        max_hp: (0, 0),
+        weight: 3,
+    },
+    AffixTemplate {
+        name: "of the Titan",
+        kind: AffixKind::Suffix,
+        applies_to: GEAR,
+        attack: (1, 2),
+        defense: (1, 1),
+        max_hp: (5, 10),
+        weight: 1,
+    },
+];
+
+impl AffixTemplate {
+    fn roll(&self) -> Affix {
+        let mut rng = rand::thread_rng();
+        let mut roll = |(lo, hi): (i32, i32)| rng.gen_range(lo..=hi.max(lo));
+        Affix {
+            name: self.name.to_string(),
+            kind: self.kind,
+            bonus: StatBonus {
+                attack: roll(self.attack),
+                defense: roll(self.defense),
+                max_hp: roll(self.max_hp),
+            },
+        }
+    }
+}
+
+// ── Generator ────────────────────────────────────────────────────────
+
+/// Chance (0.0–1.0) of rolling a prefix and, separately, a suffix.
+const PREFIX_CHANCE: f64 = 0.45;
+const SUFFIX_CHANCE: f64 = 0.35;
+
+fn pick_affix(kind: AffixKind, item_type: ItemType) -> Option<Affix> {
+    let pool: Vec<&AffixTemplate> = AFFIXES
+        .iter()
+        .filter(|a| a.kind == kind && a.applies_to.contains(&item_type))
+        .collect();
+    pool.choose_weighted(&mut rand::thread_rng(), |a| a.weight)
+        .ok()
+        .map(|a| a.roll())
+}
+
+/// Roll a randomized version of `base`: maybe a prefix, maybe a suffix
+/// ("Keen Goblin Dagger of the Bear"). Each affix raises the rarity one
+/// tier and the price by half. Items that can't be equipped come back as-is.
+pub fn generate(base: &Item) -> Item {
+    let mut item = base.clone();
+    if item.equip_slot().is_none() || !item.affixes.is_empty() {
+        return item;
+    }
+
+    let mut rng = rand::thread_rng();
+    if rng.r#gen::<f64>() < PREFIX_CHANCE
+        && let Some(prefix) = pick_affix(AffixKind::Prefix, item.item_type)
+    {
+        item.name = format!("{} {}", prefix.name, item.name);
+        item.affixes.push(prefix);
+    }
+    if rng.r#gen::<f64>() < SUFFIX_CHANCE
+        && let Some(suffix) = pick_affix(AffixKind::Suffix, item.item_type)
+    {
+        item.name = format!("{} {}", item.name, suffix.name);
+        item.affixes.push(suffix);
+    }
+
+    for _ in &item.affixes {
+        item.rarity = item.rarity.upgraded();
+        item.price += item.price / 2;
+    }
+    item
+}
@@ -0,0 +1,82 @@
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+
+/// How an enemy picks its moves.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum Behavior {
+    /// Always attacks.
+    #[default]
+    Aggressive,
+    /// Turtles up more often as its HP drops.
+    Cautious,
+    /// Bolts at low HP, taking its loot with it.
+    Cowardly,
+    /// Patches itself up when hurt.
+    Healer,
+    /// Winds up a telegraphed heavy blow.
+    Charger,
+}
+
+/// What the enemy will do on its next turn. Chosen ahead of time so the
+/// combat screen can show it and the player can respond.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EnemyIntent {
+    Attack,
+    Defend,
+    Heal,
+    Flee,
+    WindUp,
+    Unleash,
+}
+
+impl EnemyIntent {
+    pub fn telegraph(&self) -> &'static str {
+        match self {
+            EnemyIntent::Attack => "readies an attack",
+            EnemyIntent::Defend => "raises its guard",
+            EnemyIntent::Heal => "gathers healing energy",
+            EnemyIntent::Flee => "eyes an escape route",
+            EnemyIntent::WindUp => "gathers its strength",
+            EnemyIntent::Unleash => "is about to unleash a crushing blow!",
+        }
+    }
+
+    /// Intents worth warning the player about.
+    pub fn is_threatening(&self) -> bool {
+        matches!(self, EnemyIntent::Unleash | EnemyIntent::Flee)
+    }
+}
+
+impl Behavior {
+    /// Weighted move options given the enemy's current HP ratio (0.0–1.0).
+    pub fn weights(&self, hp_ratio: f64) -> Vec<(EnemyIntent, u32)> {
+        match self {
+            Behavior::Aggressive => vec![(EnemyIntent::Attack, 1)],
+            Behavior::Cautious if hp_ratio > 0.5 => {
+                vec![(EnemyIntent::Attack, 80), (EnemyIntent::Defend, 20)]
+            }
+            Behavior::Cautious => vec![(EnemyIntent::Attack, 50), (EnemyIntent::Defend, 50)],
+            Behavior::Cowardly if hp_ratio > 0.4 => {
+                vec![(EnemyIntent::Attack, 90), (EnemyIntent::Defend, 10)]
+            }
+            Behavior::Cowardly => vec![(EnemyIntent::Attack, 40), (EnemyIntent::Flee, 60)],
+            Behavior::Healer if hp_ratio >= 1.0 => vec![(EnemyIntent::Attack, 1)],
+            Behavior::Healer if hp_ratio > 0.5 => {
+                vec![(EnemyIntent::Attack, 85), (EnemyIntent::Heal, 15)]
+            }
+            Behavior::Healer => vec![(EnemyIntent::Attack, 40), (EnemyIntent::Heal, 60)],
+            Behavior::Charger => vec![(EnemyIntent::Attack, 65), (EnemyIntent::WindUp, 35)],
+        }
+    }
+
+    /// Pick the next move. A wind-up is always followed by its big hit.
+    pub fn choose_intent(&self, previous: Option<EnemyIntent>, hp_ratio: f64) -> EnemyIntent {
+        if previous == Some(EnemyIntent::WindUp) {
+            return EnemyIntent::Unleash;
+        }
+        self.weights(hp_ratio)
+            .choose_weighted(&mut rand::thread_rng(), |(_, weight)| *weight)
+            .map(|(intent, _)| *intent)
+            .unwrap_or(EnemyIntent::Attack)
+    }
+}
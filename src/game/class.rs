@@ -0,0 +1,200 @@
+use serde::{Deserialize, Serialize};
+
+use super::abilities::Ability;
+use super::player::{EquipSlot, Equipment, Item, ItemType, Player};
+use super::progression::Progression;
+
+/// A block of core stats, used both for a class's starting values and
+/// for what it gains each level.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ClassStats {
+    pub max_hp: i32,
+    pub attack: i32,
+    pub defense: i32,
+    pub stamina: i32,
+}
+
+impl ClassStats {
+    /// Short line like "+5 HP, +2 atk, +1 def, +1 sta".
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        for (value, label) in [
+            (self.max_hp, "HP"),
+            (self.attack, "atk"),
+            (self.defense, "def"),
+            (self.stamina, "sta"),
+        ] {
+            if value != 0 {
+                parts.push(format!("{:+} {}", value, label));
+            }
+        }
+        parts.join(", ")
+    }
+}
+
+impl std::ops::AddAssign for ClassStats {
+    fn add_assign(&mut self, other: Self) {
+        self.max_hp += other.max_hp;
+        self.attack += other.attack;
+        self.defense += other.defense;
+        self.stamina += other.stamina;
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CharacterClass {
+    pub name: String,
+    pub description: String,
+    pub base: ClassStats,
+    /// Added on every level-up.
+    pub growth: ClassStats,
+    /// Gear worn from the start.
+    #[serde(default)]
+    pub equipment: Equipment,
+    /// Starting bag contents.
+    #[serde(default)]
+    pub items: Vec<Item>,
+    /// Known from level 1, on top of the abilities learned by level.
+    pub signature: Ability,
+}
+
+impl CharacterClass {
+    /// A fresh level-1 hunter of this class.
+    pub fn create(&self, name: String, progression: &Progression) -> Player {
+        let mut player = Player::new(name, progression);
+        player.class = self.name.clone();
+        player.growth = self.growth;
+        player.max_hp = self.base.max_hp;
+        player.hp = self.base.max_hp;
+        player.attack = self.base.attack;
+        player.defense = self.base.defense;
+        player.max_stamina = self.base.stamina;
+        player.stamina = self.base.stamina;
+        player.equipment = self.equipment.clone();
+        player.inventory = self.items.clone();
+        player.abilities = vec![self.signature];
+        player.hp = player.total_max_hp();
+        player
+    }
+}
+
+// ── Built-in classes ─────────────────────────────────────────────────
+
+pub fn build_classes() -> Vec<CharacterClass> {
+    let small_potion = |quantity| Item {
+        name: "Small Potion".into(),
+        description: "Restores 10 HP.".into(),
+        item_type: ItemType::Potion,
+        value: 10,
+        price: 10,
+        quantity,
+        ..Default::default()
+    };
+
+    vec![
+        CharacterClass {
+            name: "Warrior".into(),
+            description: "A sturdy fighter who trusts a shield and a long day's \
+                work. Hardest to kill."
+                .into(),
+            base: ClassStats {
+                max_hp: 40,
+                attack: 5,
+                defense: 3,
+                stamina: 10,
+            },
+            growth: ClassStats {
+                max_hp: 7,
+                attack: 2,
+                defense: 1,
+                stamina: 1,
+            },
+            equipment: Equipment {
+                main_hand: Some(Item {
+                    name: "Rusty Sword".into(),
+                    description: "A worn but serviceable blade.".into(),
+                    item_type: ItemType::Weapon,
+                    value: 3,
+                    price: 5,
+                    ..Default::default()
+                }),
+                off_hand: Some(Item {
+                    name: "Wooden Shield".into(),
+                    description: "Planks and a leather strap. +1 def.".into(),
+                    item_type: ItemType::Armor,
+                    value: 1,
+                    slot: Some(EquipSlot::OffHand),
+                    price: 8,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            items: vec![small_potion(1)],
+            signature: Ability::ShieldBash,
+        },
+        CharacterClass {
+            name: "Ranger".into(),
+            description: "A quick-footed tracker who hits hard and keeps moving. \
+                Grows into the strongest striker."
+                .into(),
+            base: ClassStats {
+                max_hp: 30,
+                attack: 6,
+                defense: 2,
+                stamina: 12,
+            },
+            growth: ClassStats {
+                max_hp: 5,
+                attack: 3,
+                defense: 1,
+                stamina: 1,
+            },
+            equipment: Equipment {
+                main_hand: Some(Item {
+                    name: "Hunting Bow".into(),
+                    description: "Ash wood and gut string.".into(),
+                    item_type: ItemType::Weapon,
+                    value: 4,
+                    price: 12,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            items: vec![small_potion(1)],
+            signature: Ability::Volley,
+        },
+        CharacterClass {
+            name: "Alchemist".into(),
+            description: "A brewer of cures and worse. Frail, but never short of \
+                potions or tricks."
+                .into(),
+            base: ClassStats {
+                max_hp: 26,
+                attack: 4,
+                defense: 2,
+                stamina: 12,
+            },
+            growth: ClassStats {
+                max_hp: 4,
+                attack: 2,
+                defense: 1,
+                stamina: 2,
+            },
+            equipment: Equipment {
+                main_hand: Some(Item {
+                    name: "Walking Staff".into(),
+                    description: "Knotted oak, good for leaning on and for cracking skulls."
+                        .into(),
+                    item_type: ItemType::Weapon,
+                    value: 2,
+                    price: 4,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            items: vec![small_potion(3)],
+            signature: Ability::FireFlask,
+        },
+    ]
+}
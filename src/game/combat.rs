@@ -1,26 +1,27 @@
-use super::dice::Dice;
+use std::fmt;
+
+use super::abilities::Ability;
+use super::behavior::{Behavior, EnemyIntent};
+use super::dice::{Dice, DiceResult, Keep};
+use super::loot::LootTable;
 use super::player::Player;
-use super::world::EnemyTemplate;
+use super::status::{Affliction, StatusEffect, StatusEffects, StatusKind, TickReport};
+use super::world::{BossPhase, EnemyTemplate, PhaseTrigger};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CombatAction {
     Attack,
+    Ability(Ability),
     Defend,
     UsePotion,
     Flee,
 }
 
 impl CombatAction {
-    pub const ALL: [CombatAction; 4] = [
-        CombatAction::Attack,
-        CombatAction::Defend,
-        CombatAction::UsePotion,
-        CombatAction::Flee,
-    ];
-
     pub fn label(&self) -> &'static str {
         match self {
             CombatAction::Attack => "Attack",
+            CombatAction::Ability(ability) => ability.label(),
             CombatAction::Defend => "Defend",
             CombatAction::UsePotion => "Use Potion",
             CombatAction::Flee => "Flee",
@@ -34,6 +35,105 @@ pub enum CombatPhase {
     Victory,
     Defeat,
     Fled,
+    /// The enemy ran off — no EXP, no loot.
+    EnemyFled,
+}
+
+/// Damage multiplier on a natural 20.
+const CRIT_MULTIPLIER: i32 = 2;
+
+/// Fleeing succeeds on 1d20 + the player's flee bonus >= this.
+pub const FLEE_DC: i32 = 11;
+
+pub fn flee_dice(player: &Player) -> Dice {
+    Dice::new(1, 20, player.flee_bonus())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HitOutcome {
+    /// Natural 20 (or the attacker's crit range): always hits, damage
+    /// multiplied.
+    Critical,
+    Hit,
+    Miss,
+    /// Natural 1: always misses.
+    Fumble,
+}
+
+impl HitOutcome {
+    pub fn label(&self) -> &'static str {
+        match self {
+            HitOutcome::Critical => "CRITICAL HIT",
+            HitOutcome::Hit => "hit",
+            HitOutcome::Miss => "miss",
+            HitOutcome::Fumble => "FUMBLE",
+        }
+    }
+
+    pub fn is_hit(&self) -> bool {
+        matches!(self, HitOutcome::Critical | HitOutcome::Hit)
+    }
+
+    pub fn damage_multiplier(&self) -> i32 {
+        match self {
+            HitOutcome::Critical => CRIT_MULTIPLIER,
+            _ => 1,
+        }
+    }
+}
+
+/// A d20 + accuracy roll against a target's armor class.
+pub struct AttackRoll {
+    pub roll: DiceResult,
+    pub armor_class: i32,
+    pub outcome: HitOutcome,
+}
+
+impl AttackRoll {
+    /// `keep` gives the d20 advantage or disadvantage.
+    pub fn roll(accuracy: i32, armor_class: i32, keep: Keep) -> Self {
+        Self::roll_with_crit(accuracy, armor_class, keep, 20)
+    }
+
+    /// As `roll`, critting on any natural roll of `crit_on` or more.
+    pub fn roll_with_crit(accuracy: i32, armor_class: i32, keep: Keep, crit_on: u32) -> Self {
+        let roll = Dice::new(1, 20, accuracy).with_keep(keep).roll();
+        let outcome = match roll.natural() {
+            n if n >= crit_on => HitOutcome::Critical,
+            1 => HitOutcome::Fumble,
+            _ if roll.total >= armor_class => HitOutcome::Hit,
+            _ => HitOutcome::Miss,
+        };
+        Self {
+            roll,
+            armor_class,
+            outcome,
+        }
+    }
+}
+
+impl fmt::Display for AttackRoll {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} vs AC {} — {}",
+            self.roll,
+            self.armor_class,
+            self.outcome.label()
+        )
+    }
+}
+
+/// A summoned add fighting alongside a boss. Minions soak the player's
+/// attacks until they fall.
+#[derive(Debug, Clone)]
+pub struct Minion {
+    pub name: String,
+    pub hp: i32,
+    pub max_hp: i32,
+    pub damage_dice: Dice,
+    pub armor: i32,
+    pub accuracy: i32,
 }
 
 #[derive(Debug, Clone)]
@@ -42,52 +142,152 @@ pub struct CombatState {
     pub enemy_hp: i32,
     pub enemy_max_hp: i32,
     pub enemy_damage_dice: Dice,
+    /// `enemy_damage_dice.summary()`, kept so the screen doesn't rebuild
+    /// the distribution every frame. Refreshed when a boss phase changes
+    /// the dice.
+    pub enemy_damage_summary: String,
+    pub enemy_armor: i32,
+    pub enemy_accuracy: i32,
+    pub behavior: Behavior,
+    /// The enemy's telegraphed move for its upcoming turn.
+    pub intent: EnemyIntent,
     pub exp_reward: u32,
     pub is_boss: bool,
-    pub loot: Option<super::player::Item>,
+    pub phases: Vec<BossPhase>,
+    /// Index of the boss phase currently in effect, if any.
+    pub current_phase: Option<usize>,
+    pub entered_phases: Vec<usize>,
+    pub minions: Vec<Minion>,
+    /// Rounds completed so far.
+    pub turn: u32,
+    pub loot: Vec<LootTable>,
+    /// Gold dropped on defeat.
+    pub gold: Option<Dice>,
     pub phase: CombatPhase,
     pub selected_action: usize,
     pub defending: bool,
+    /// Remaining cooldown turns per ability used this fight.
+    pub cooldowns: Vec<(Ability, u32)>,
+    pub enemy_inflicts: Option<Affliction>,
+    pub player_effects: StatusEffects,
+    pub enemy_effects: StatusEffects,
     pub log: Vec<String>,
+    /// Chance the player's flee roll succeeds. Flee bonuses can't change
+    /// mid-fight, so this is worked out once.
+    pub flee_odds: f64,
 }
 
 impl CombatState {
-    pub fn from_template(template: &EnemyTemplate) -> Self {
+    pub fn from_template(template: &EnemyTemplate, player: &Player) -> Self {
         Self {
             enemy_name: template.name.clone(),
             enemy_hp: template.hp,
             enemy_max_hp: template.hp,
             enemy_damage_dice: template.damage_dice.clone(),
+            enemy_damage_summary: template.damage_dice.summary(),
+            enemy_armor: template.armor,
+            enemy_accuracy: template.accuracy,
+            behavior: template.behavior,
+            intent: template.behavior.choose_intent(None, 1.0),
             exp_reward: template.exp_reward,
             is_boss: template.is_boss,
+            phases: template.phases.clone(),
+            current_phase: None,
+            entered_phases: Vec::new(),
+            minions: Vec::new(),
+            turn: 0,
             loot: template.loot.clone(),
+            gold: template.gold.clone(),
             phase: CombatPhase::PlayerChoosing,
             selected_action: 0,
             defending: false,
+            cooldowns: Vec::new(),
+            enemy_inflicts: template.inflicts.clone(),
+            player_effects: StatusEffects::default(),
+            enemy_effects: StatusEffects::default(),
             log: vec![format!("A {} appears!", template.name)],
+            flee_odds: flee_dice(player).chance_at_least(FLEE_DC),
+        }
+    }
+
+    /// The player's move list: attack, known abilities, then the basics.
+    pub fn actions(&self, player: &Player) -> Vec<CombatAction> {
+        let mut actions = vec![CombatAction::Attack];
+        actions.extend(player.abilities.iter().map(|&a| CombatAction::Ability(a)));
+        actions.extend([
+            CombatAction::Defend,
+            CombatAction::UsePotion,
+            CombatAction::Flee,
+        ]);
+        actions
+    }
+
+    pub fn cooldown(&self, ability: Ability) -> u32 {
+        self.cooldowns
+            .iter()
+            .find(|(a, _)| *a == ability)
+            .map(|(_, turns)| *turns)
+            .unwrap_or(0)
+    }
+
+    /// Why an ability can't be used right now, if it can't.
+    pub fn ability_blocked(&self, ability: Ability, player: &Player) -> Option<String> {
+        let cooldown = self.cooldown(ability);
+        if cooldown > 0 {
+            Some(format!(
+                "{} is recovering ({} more turn(s)).",
+                ability.label(),
+                cooldown
+            ))
+        } else if player.stamina < ability.cost() {
+            Some(format!(
+                "Not enough stamina for {} ({} needed).",
+                ability.label(),
+                ability.cost()
+            ))
+        } else {
+            None
         }
     }
 
     pub fn execute_action(&mut self, player: &mut Player) {
-        let action = CombatAction::ALL[self.selected_action].clone();
+        let action = self.actions(player)[self.selected_action];
+
+        // Unusable abilities don't cost the turn
+        if let CombatAction::Ability(ability) = action
+            && let Some(reason) = self.ability_blocked(ability, player)
+        {
+            self.log.push(reason);
+            return;
+        }
+
         self.defending = false;
+        for (_, turns) in &mut self.cooldowns {
+            *turns = turns.saturating_sub(1);
+        }
+        self.cooldowns.retain(|(_, turns)| *turns > 0);
+        player.restore_stamina(1);
+
+        // Lingering effects on the player resolve before they act
+        let report = self.player_effects.tick();
+        let stunned = report.stunned;
+        if self.resolve_player_tick(player, report) {
+            return;
+        }
 
         match action {
+            _ if stunned => {
+                self.log.push("You are stunned and cannot act!".into());
+            }
             CombatAction::Attack => {
-                // Player rolls: 1d6 + total_attack bonus (weapon + level)
+                // Player rolls: 1d6 + total_attack bonus (equipment + level)
                 let attack_dice = Dice::new(1, 6, player.total_attack());
-                let result = attack_dice.roll();
-                let damage = result.total;
-                self.enemy_hp = (self.enemy_hp - damage).max(0);
-                self.log.push(format!(
-                    "You attack! ({}) = {} damage!",
-                    result, damage
-                ));
-
-                if self.enemy_hp <= 0 {
-                    self.phase = CombatPhase::Victory;
-                    self.log
-                        .push(format!("The {} is defeated!", self.enemy_name));
+                if self.player_strike(player, attack_dice, "You attack!", Keep::All) {
+                    return;
+                }
+            }
+            CombatAction::Ability(ability) => {
+                if self.use_ability(ability, player) {
                     return;
                 }
             }
@@ -97,17 +297,23 @@ impl CombatState {
                     .push("You brace yourself, reducing incoming damage.".into());
             }
             CombatAction::UsePotion => {
-                if let Some(healed) = player.use_potion() {
-                    self.log
-                        .push(format!("You drink a potion and recover {} HP.", healed));
+                let hp_before = player.hp;
+                if let Some(potion) = player.use_potion() {
+                    self.log.push(format!(
+                        "You drink a potion and recover {} HP.",
+                        player.hp - hp_before
+                    ));
+                    if let Some(effect) = potion.effect.as_ref().and_then(|a| a.roll()) {
+                        self.afflict_player(effect);
+                    }
                 } else {
                     self.log.push("You have no potions!".into());
                 }
             }
             CombatAction::Flee => {
-                // Roll 1d20 — flee on 11+ (50%)
-                let flee_roll = Dice::new(1, 20, 0).roll();
-                if flee_roll.total >= 11 {
+                // Roll 1d20 + flee bonus — flee on 11+
+                let flee_roll = flee_dice(player).roll();
+                if flee_roll.total >= FLEE_DC {
                     self.phase = CombatPhase::Fled;
                     self.log.push(format!(
                         "You roll to flee ({}) — escaped!",
@@ -127,35 +333,495 @@ impl CombatState {
         self.enemy_turn(player);
     }
 
+    /// Roll to hit, then damage, against the first minion standing or the
+    /// enemy itself, applying weapon effects on hit. Returns true if the
+    /// enemy was defeated.
+    fn player_strike(&mut self, player: &mut Player, dice: Dice, verb: &str, keep: Keep) -> bool {
+        let armor_class = self
+            .minions
+            .first()
+            .map(|m| m.armor)
+            .unwrap_or(self.enemy_armor);
+        let to_hit =
+            AttackRoll::roll_with_crit(player.accuracy(), armor_class, keep, player.crit_on());
+        if !to_hit.outcome.is_hit() {
+            self.log.push(format!("{} ({})!", verb, to_hit));
+            return false;
+        }
+
+        let result = dice.roll();
+        let multiplier = to_hit.outcome.damage_multiplier();
+        let mut damage = self.player_effects.modify_damage(result.total * multiplier);
+        let crit = if multiplier > 1 {
+            format!(" ×{}", multiplier)
+        } else {
+            String::new()
+        };
+        if self.intent == EnemyIntent::Defend {
+            damage = (damage / 2).max(1);
+            self.log.push(format!(
+                "{} ({})! ({}){} — the {} is guarding! {} damage.",
+                verb, to_hit, result, crit, self.enemy_name, damage
+            ));
+        } else {
+            self.log.push(format!(
+                "{} ({})! ({}){} = {} damage!",
+                verb, to_hit, result, crit, damage
+            ));
+        }
+        player.stats.record_hit(damage);
+        if self.hit_minion(damage) {
+            return false;
+        }
+        self.enemy_hp = (self.enemy_hp - damage).max(0);
+
+        if self.enemy_hp <= 0 {
+            self.phase = CombatPhase::Victory;
+            self.log
+                .push(format!("The {} is defeated!", self.enemy_name));
+            return true;
+        }
+
+        if let Some(effect) = player
+            .weapon()
+            .and_then(|w| w.effect.as_ref())
+            .and_then(|a| a.roll())
+        {
+            self.afflict_enemy(effect);
+        }
+        false
+    }
+
+    /// Spend stamina, start the cooldown and resolve the ability.
+    /// Returns true if the enemy was defeated.
+    fn use_ability(&mut self, ability: Ability, player: &mut Player) -> bool {
+        player.stamina -= ability.cost();
+        self.cooldowns.push((ability, ability.cooldown()));
+
+        match ability {
+            Ability::PowerStrike => {
+                let dice = Dice::new(2, 6, player.total_attack());
+                self.player_strike(player, dice, "You unleash a Power Strike!", Keep::All)
+            }
+            Ability::AimedShot => {
+                let dice = Dice::new(1, 6, player.total_attack() + 2);
+                let hp_before = self.enemy_hp;
+                if self.player_strike(player, dice, "You take an Aimed Shot!", Keep::Advantage) {
+                    return true;
+                }
+                if self.enemy_hp < hp_before {
+                    self.afflict_enemy(StatusEffect::new(StatusKind::Bleed, 3, 2));
+                }
+                false
+            }
+            Ability::SecondWind => {
+                let amount = (player.total_max_hp() / 4).max(1);
+                player.heal(amount);
+                self.log
+                    .push(format!("You catch your Second Wind and recover {} HP.", amount));
+                self.afflict_player(StatusEffect::new(StatusKind::Regeneration, 3, 2));
+                false
+            }
+            Ability::Taunt => {
+                self.log
+                    .push(format!("You taunt the {}, goading it into reckless swings.", self.enemy_name));
+                self.afflict_enemy(StatusEffect::new(StatusKind::Weakened, 2, 30));
+                false
+            }
+            Ability::ShieldBash => {
+                let dice = Dice::new(1, 4, player.total_attack());
+                let hp_before = self.enemy_hp;
+                if self.player_strike(player, dice, "You Shield Bash", Keep::All) {
+                    return true;
+                }
+                if self.enemy_hp < hp_before {
+                    self.afflict_enemy(StatusEffect::new(StatusKind::Stun, 1, 1));
+                }
+                false
+            }
+            Ability::Volley => {
+                let dice = Dice::new(1, 4, player.total_attack() / 2);
+                self.player_strike(player, dice.clone(), "Your first arrow flies", Keep::All)
+                    || self.player_strike(player, dice, "Your second arrow flies", Keep::All)
+            }
+            Ability::FireFlask => {
+                self.log.push(format!(
+                    "You hurl a Fire Flask and the {} bursts into flame!",
+                    self.enemy_name
+                ));
+                self.afflict_enemy(StatusEffect::new(StatusKind::Burn, 3, 3));
+                false
+            }
+        }
+    }
+
     fn enemy_turn(&mut self, player: &mut Player) {
+        // Lingering effects on the enemy resolve before it acts
+        let report = self.enemy_effects.tick();
+        let stunned = report.stunned;
+        if self.resolve_enemy_tick(player, report) {
+            return;
+        }
+        self.turn += 1;
+        self.check_boss_phase();
+        if stunned {
+            self.log
+                .push(format!("The {} is stunned and cannot act!", self.enemy_name));
+            self.intent = self.behavior.choose_intent(None, self.enemy_hp_ratio());
+        } else {
+            self.enemy_act(player);
+            if self.phase == CombatPhase::EnemyFled {
+                return;
+            }
+        }
+        self.minions_attack(player);
+
+        if !player.is_alive() {
+            self.phase = CombatPhase::Defeat;
+            self.log.push("You have been slain...".into());
+            return;
+        }
+        self.phase = CombatPhase::PlayerChoosing;
+    }
+
+    /// Carry out the telegraphed intent, then pick the next one.
+    fn enemy_act(&mut self, player: &mut Player) {
+        match self.intent {
+            EnemyIntent::Attack => self.enemy_attack(player, 1, "attacks!"),
+            EnemyIntent::Unleash => self.enemy_attack(player, 2, "unleashes a crushing blow!"),
+            EnemyIntent::Defend => {
+                self.log
+                    .push(format!("The {} holds its guard.", self.enemy_name));
+            }
+            EnemyIntent::WindUp => {
+                self.log.push(format!(
+                    "The {} rears back, winding up a massive blow...",
+                    self.enemy_name
+                ));
+            }
+            EnemyIntent::Heal => {
+                let amount = (self.enemy_max_hp / 5).max(1);
+                self.enemy_hp = (self.enemy_hp + amount).min(self.enemy_max_hp);
+                self.log.push(format!(
+                    "The {} mends its wounds and recovers {} HP.",
+                    self.enemy_name, amount
+                ));
+            }
+            EnemyIntent::Flee => {
+                self.phase = CombatPhase::EnemyFled;
+                self.log.push(format!(
+                    "The {} turns tail and escapes — taking its loot with it!",
+                    self.enemy_name
+                ));
+                return;
+            }
+        }
+
+        self.intent = self
+            .behavior
+            .choose_intent(Some(self.intent), self.enemy_hp_ratio());
+    }
+
+    /// Roll the enemy's damage dice (times `multiplier`) against the player.
+    fn enemy_attack(&mut self, player: &mut Player, multiplier: i32, verb: &str) {
+        let to_hit = AttackRoll::roll(self.enemy_accuracy, player.armor_class(), self.enemy_keep());
+        if !to_hit.outcome.is_hit() {
+            self.log.push(format!(
+                "The {} {} ({})!",
+                self.enemy_name, verb, to_hit
+            ));
+            return;
+        }
+
         let result = self.enemy_damage_dice.roll();
-        let mut damage = result.total;
+        let multiplier = multiplier * to_hit.outcome.damage_multiplier();
+        let mut damage = self.enemy_effects.modify_damage(result.total * multiplier);
 
         if self.defending {
             damage /= 2;
             damage = damage.max(1);
         }
 
-        let reduced = (damage - player.defense).max(1);
+        let reduced = player
+            .difficulty
+            .scale_damage((damage - player.total_defense()).max(1));
         player.hp = (player.hp - reduced).max(0);
+        player.stats.record_damage_taken(reduced);
 
+        let times = if multiplier > 1 {
+            format!(" ×{}", multiplier)
+        } else {
+            String::new()
+        };
         if self.defending {
             self.log.push(format!(
-                "The {} attacks! ({}) — blocked! {} damage taken.",
-                self.enemy_name, result, reduced
+                "The {} {} ({})! ({}){} — blocked! {} damage taken.",
+                self.enemy_name, verb, to_hit, result, times, reduced
             ));
         } else {
             self.log.push(format!(
-                "The {} attacks! ({}) = {} damage!",
-                self.enemy_name, result, reduced
+                "The {} {} ({})! ({}){} = {} damage!",
+                self.enemy_name, verb, to_hit, result, times, reduced
             ));
         }
 
+        if player.is_alive()
+            && let Some(effect) = self.enemy_inflicts.as_ref().and_then(|a| a.roll())
+        {
+            self.afflict_player(effect);
+        }
+    }
+
+    // ── Boss phases & minions ────────────────────────────────────────
+
+    pub fn phase_name(&self) -> Option<&str> {
+        self.current_phase.map(|i| self.phases[i].name.as_str())
+    }
+
+    /// Enter the next phase in order once its trigger has been met. A later
+    /// phase never jumps the queue, even if its own trigger fires first.
+    fn check_boss_phase(&mut self) {
+        let ratio = self.enemy_hp_ratio();
+        let index = self.entered_phases.len();
+        let Some(phase) = self.phases.get(index) else {
+            return;
+        };
+        let triggered = match phase.trigger {
+            PhaseTrigger::HpBelow(threshold) => ratio <= threshold,
+            PhaseTrigger::Turn(turn) => self.turn >= turn,
+        };
+        if !triggered {
+            return;
+        }
+        let phase = phase.clone();
+
+        self.entered_phases.push(index);
+        self.current_phase = Some(index);
+        self.log.push(format!(
+            "══ {} — {} ══",
+            self.enemy_name.to_uppercase(),
+            phase.name.to_uppercase()
+        ));
+        self.log.push(phase.narration.clone());
+
+        if let Some(dice) = phase.damage_dice {
+            self.log
+                .push(format!("Its attacks grow stronger! (ATK {})", dice));
+            self.enemy_damage_summary = dice.summary();
+            self.enemy_damage_dice = dice;
+        }
+        if let Some(behavior) = phase.behavior {
+            self.behavior = behavior;
+            self.intent = behavior.choose_intent(None, ratio);
+        }
+        for template in &phase.minions {
+            self.log.push(format!("A {} joins the fight!", template.name));
+            self.minions.push(Minion {
+                name: template.name.clone(),
+                hp: template.hp,
+                max_hp: template.hp,
+                damage_dice: template.damage_dice.clone(),
+                armor: template.armor,
+                accuracy: template.accuracy,
+            });
+        }
+        if let Some(effect) = phase.arena_effect {
+            self.afflict_player(effect);
+        }
+    }
+
+    /// Route damage to the first standing minion. Returns true if a minion
+    /// took the hit (the boss is shielded).
+    fn hit_minion(&mut self, damage: i32) -> bool {
+        let Some(minion) = self.minions.first_mut() else {
+            return false;
+        };
+        minion.hp = (minion.hp - damage).max(0);
+        if minion.hp == 0 {
+            let minion = self.minions.remove(0);
+            self.log.push(format!(
+                "The {} shields its master — and falls!",
+                minion.name
+            ));
+        } else {
+            self.log.push(format!(
+                "The {} shields its master and takes the blow.",
+                minion.name
+            ));
+        }
+        true
+    }
+
+    fn minions_attack(&mut self, player: &mut Player) {
+        for minion in &self.minions {
+            if !player.is_alive() {
+                break;
+            }
+            let to_hit = AttackRoll::roll(minion.accuracy, player.armor_class(), self.enemy_keep());
+            if !to_hit.outcome.is_hit() {
+                self.log
+                    .push(format!("The {} strikes ({})!", minion.name, to_hit));
+                continue;
+            }
+            let result = minion.damage_dice.roll();
+            let mut damage = result.total * to_hit.outcome.damage_multiplier();
+            if self.defending {
+                damage = (damage / 2).max(1);
+            }
+            let reduced = player
+                .difficulty
+                .scale_damage((damage - player.total_defense()).max(1));
+            player.hp = (player.hp - reduced).max(0);
+            player.stats.record_damage_taken(reduced);
+            self.log.push(format!(
+                "The {} strikes ({})! ({}) = {} damage!",
+                minion.name, to_hit, result, reduced
+            ));
+        }
+    }
+
+    /// Enemies swing at disadvantage against a defending player.
+    fn enemy_keep(&self) -> Keep {
+        if self.defending {
+            Keep::Disadvantage
+        } else {
+            Keep::All
+        }
+    }
+
+    pub fn enemy_hp_ratio(&self) -> f64 {
+        if self.enemy_max_hp > 0 {
+            self.enemy_hp as f64 / self.enemy_max_hp as f64
+        } else {
+            0.0
+        }
+    }
+
+    // ── Status effects ───────────────────────────────────────────────
+
+    pub fn afflict_player(&mut self, effect: StatusEffect) {
+        if effect.kind.is_harmful() {
+            self.log.push(format!("You are afflicted: {}!", effect));
+        } else {
+            self.log.push(format!("You gain {}.", effect));
+        }
+        self.player_effects.apply(effect);
+    }
+
+    pub fn afflict_enemy(&mut self, effect: StatusEffect) {
+        if effect.kind.is_harmful() {
+            self.log
+                .push(format!("The {} is afflicted: {}!", self.enemy_name, effect));
+        } else {
+            self.log
+                .push(format!("The {} gains {}.", self.enemy_name, effect));
+        }
+        self.enemy_effects.apply(effect);
+    }
+
+    /// Apply a player tick report. Returns true if the player died.
+    fn resolve_player_tick(&mut self, player: &mut Player, report: TickReport) -> bool {
+        for (kind, damage) in report.damage {
+            let damage = player.difficulty.scale_damage(damage);
+            player.hp = (player.hp - damage).max(0);
+            player.stats.record_damage_taken(damage);
+            self.log
+                .push(format!("{} deals {} damage to you.", kind.label(), damage));
+        }
+        if report.healing > 0 && player.is_alive() {
+            player.heal(report.healing);
+            self.log
+                .push(format!("You regenerate {} HP.", report.healing));
+        }
+        for kind in report.expired {
+            self.log.push(format!("Your {} wears off.", kind.label()));
+        }
+
         if !player.is_alive() {
             self.phase = CombatPhase::Defeat;
             self.log.push("You have been slain...".into());
-        } else {
-            self.phase = CombatPhase::PlayerChoosing;
+            return true;
+        }
+        false
+    }
+
+    /// Apply an enemy tick report. Returns true if the enemy died.
+    fn resolve_enemy_tick(&mut self, player: &mut Player, report: TickReport) -> bool {
+        for (kind, damage) in report.damage {
+            self.enemy_hp = (self.enemy_hp - damage).max(0);
+            player.stats.record_tick(damage);
+            self.log.push(format!(
+                "{} deals {} damage to the {}.",
+                kind.label(),
+                damage,
+                self.enemy_name
+            ));
+        }
+        if report.healing > 0 && self.enemy_hp > 0 {
+            self.enemy_hp = (self.enemy_hp + report.healing).min(self.enemy_max_hp);
+            self.log.push(format!(
+                "The {} regenerates {} HP.",
+                self.enemy_name, report.healing
+            ));
+        }
+        for kind in report.expired {
+            self.log.push(format!(
+                "The {}'s {} wears off.",
+                self.enemy_name,
+                kind.label()
+            ));
+        }
+
+        if self.enemy_hp <= 0 {
+            self.phase = CombatPhase::Victory;
+            self.log
+                .push(format!("The {} is defeated!", self.enemy_name));
+            return true;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::progression::Progression;
+    use crate::game::world::Respawn;
+
+    fn template(armor: i32) -> EnemyTemplate {
+        EnemyTemplate {
+            name: "Target".into(),
+            hp: 1000,
+            damage_dice: Dice::new(1, 4, 0),
+            exp_reward: 0,
+            loot: Vec::new(),
+            is_boss: false,
+            armor,
+            accuracy: 0,
+            inflicts: None,
+            behavior: Behavior::default(),
+            phases: Vec::new(),
+            respawn: Respawn::AfterMinutes(5),
+            gold: None,
+        }
+    }
+
+    #[test]
+    fn aimed_shot_only_bleeds_the_enemy_when_it_lands() {
+        let mut player = Player::new("Test".into(), &Progression::default());
+        let mut missed = false;
+        for _ in 0..200 {
+            // Only a natural 20 can beat this armor.
+            let mut combat = CombatState::from_template(&template(1000), &player);
+            player.stamina = player.max_stamina.max(Ability::AimedShot.cost());
+            combat.use_ability(Ability::AimedShot, &mut player);
+            if combat.enemy_hp == combat.enemy_max_hp {
+                missed = true;
+                assert!(combat.enemy_effects.active.is_empty());
+            } else {
+                assert_eq!(combat.enemy_effects.potency(StatusKind::Bleed), 2);
+            }
         }
+        assert!(missed);
     }
 }
@@ -0,0 +1,196 @@
+use serde::{Deserialize, Serialize};
+
+use super::player::{Item, Player};
+
+/// So many of an item, matched by its base name (affixes ignored).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ingredient {
+    pub name: String,
+    #[serde(default = "one")]
+    pub quantity: u32,
+}
+
+fn one() -> u32 {
+    1
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Recipe {
+    pub ingredients: Vec<Ingredient>,
+    pub output: Item,
+    /// Known from the start; otherwise learned the first time the hunter
+    /// brings every ingredient to a workbench.
+    #[serde(default)]
+    pub starts_known: bool,
+}
+
+impl Recipe {
+    pub fn name(&self) -> &str {
+        &self.output.name
+    }
+
+    pub fn is_known(&self, player: &Player) -> bool {
+        self.starts_known || player.known_recipes.iter().any(|r| r == self.name())
+    }
+
+    /// Ingredients the bag is short of, as (ingredient, have).
+    pub fn missing<'a>(&'a self, player: &Player) -> Vec<(&'a Ingredient, u32)> {
+        self.ingredients
+            .iter()
+            .map(|ingredient| (ingredient, player.count_item(&ingredient.name)))
+            .filter(|(ingredient, have)| *have < ingredient.quantity)
+            .collect()
+    }
+
+    /// Use up the ingredients and return the crafted item. The caller bags
+    /// it; nothing is used up unless it will fit.
+    pub fn craft(&self, player: &mut Player) -> Result<Item, String> {
+        if !self.is_known(player) {
+            return Err("You haven't worked out that recipe yet.".into());
+        }
+        if let Some((ingredient, have)) = self.missing(player).first() {
+            return Err(format!(
+                "You need {} {} but have {}.",
+                ingredient.quantity, ingredient.name, have
+            ));
+        }
+        // Using up the ingredients may free a slot, so check on a copy
+        let mut after = player.clone();
+        for ingredient in &self.ingredients {
+            after.remove_items(&ingredient.name, ingredient.quantity);
+        }
+        if !after.can_carry(&self.output) {
+            return Err(format!(
+                "Your pack is full. Make room for the {} first.",
+                self.output.name
+            ));
+        }
+        player.inventory = after.inventory;
+        Ok(self.output.clone())
+    }
+}
+
+/// Learn every unknown recipe whose ingredients are all in the bag, at
+/// least one of each. Returns the names of the newly learned ones.
+pub fn discover(recipes: &[Recipe], player: &mut Player) -> Vec<String> {
+    let found: Vec<String> = recipes
+        .iter()
+        .filter(|recipe| !recipe.is_known(player))
+        .filter(|recipe| {
+            recipe
+                .ingredients
+                .iter()
+                .all(|ingredient| player.count_item(&ingredient.name) > 0)
+        })
+        .map(|recipe| recipe.name().to_string())
+        .collect();
+    player.known_recipes.extend(found.iter().cloned());
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::player::{ItemType, BAG_CAPACITY};
+    use crate::game::progression::Progression;
+
+    fn item(name: &str, item_type: ItemType, quantity: u32) -> Item {
+        Item {
+            name: name.into(),
+            item_type,
+            quantity,
+            ..Default::default()
+        }
+    }
+
+    fn salve() -> Recipe {
+        Recipe {
+            ingredients: vec![
+                Ingredient {
+                    name: "Herb".into(),
+                    quantity: 2,
+                },
+                Ingredient {
+                    name: "Water".into(),
+                    quantity: 1,
+                },
+            ],
+            output: item("Salve", ItemType::Potion, 1),
+            starts_known: true,
+        }
+    }
+
+    fn hunter(bag: Vec<Item>) -> Player {
+        let mut player = Player::new("Test".into(), &Progression::default());
+        player.inventory = bag;
+        player
+    }
+
+    #[test]
+    fn craft_uses_up_exactly_the_ingredients() {
+        let mut player = hunter(vec![
+            item("Herb", ItemType::Misc, 3),
+            item("Water", ItemType::Misc, 1),
+        ]);
+        let crafted = salve().craft(&mut player).unwrap();
+        assert_eq!(crafted.name, "Salve");
+        assert_eq!(player.count_item("Herb"), 1);
+        assert_eq!(player.count_item("Water"), 0);
+    }
+
+    #[test]
+    fn craft_refuses_when_short_and_takes_nothing() {
+        let mut player = hunter(vec![
+            item("Herb", ItemType::Misc, 1),
+            item("Water", ItemType::Misc, 1),
+        ]);
+        let err = salve().craft(&mut player).unwrap_err();
+        assert!(err.contains("Herb"), "{}", err);
+        assert_eq!(player.count_item("Herb"), 1);
+        assert_eq!(player.count_item("Water"), 1);
+    }
+
+    #[test]
+    fn craft_refuses_unknown_recipes() {
+        let mut player = hunter(vec![
+            item("Herb", ItemType::Misc, 2),
+            item("Water", ItemType::Misc, 1),
+        ]);
+        let recipe = Recipe {
+            starts_known: false,
+            ..salve()
+        };
+        assert!(recipe.craft(&mut player).is_err());
+        assert_eq!(player.count_item("Herb"), 2);
+
+        assert_eq!(discover(std::slice::from_ref(&recipe), &mut player), vec!["Salve"]);
+        assert!(recipe.craft(&mut player).is_ok());
+    }
+
+    #[test]
+    fn craft_keeps_the_ingredients_when_the_result_will_not_fit() {
+        let mut bag: Vec<Item> = (0..BAG_CAPACITY - 2)
+            .map(|i| item(&format!("Key {}", i), ItemType::Key, 1))
+            .collect();
+        bag.push(item("Herb", ItemType::Misc, 3));
+        bag.push(item("Water", ItemType::Misc, 2));
+        let mut player = hunter(bag);
+
+        assert!(salve().craft(&mut player).is_err());
+        assert_eq!(player.count_item("Herb"), 3);
+        assert_eq!(player.count_item("Water"), 2);
+    }
+
+    #[test]
+    fn craft_counts_the_slot_freed_by_a_used_up_stack() {
+        let mut bag: Vec<Item> = (0..BAG_CAPACITY - 2)
+            .map(|i| item(&format!("Key {}", i), ItemType::Key, 1))
+            .collect();
+        bag.push(item("Herb", ItemType::Misc, 3));
+        bag.push(item("Water", ItemType::Misc, 1));
+        let mut player = hunter(bag);
+
+        assert!(salve().craft(&mut player).is_ok());
+        assert_eq!(player.count_item("Water"), 0);
+    }
+}
@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+
+use super::player::{Item, Player};
+use super::quest::{Quest, QuestStatus};
+use super::world::WorldState;
+
+// ── Conditions and effects ───────────────────────────────────────────
+
+/// Gate on a dialogue choice. A choice shows only if all of its
+/// conditions hold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Condition {
+    MinLevel(u32),
+    /// At least this many of the item (by base name) in the bag.
+    HasItem { name: String, quantity: u32 },
+    BossDefeated(String),
+    QuestNotStarted(String),
+    QuestActive(String),
+    /// Active with every objective met, waiting to be handed in.
+    QuestReady(String),
+    QuestCompleted(String),
+    Flag(String),
+    NotFlag(String),
+}
+
+impl Condition {
+    pub fn is_met(&self, player: &Player, world: &WorldState, quests: &[Quest]) -> bool {
+        match self {
+            Condition::MinLevel(level) => player.level >= *level,
+            Condition::HasItem { name, quantity } => player.count_item(name) >= *quantity,
+            Condition::BossDefeated(name) => world.defeated_bosses.contains(name),
+            Condition::QuestNotStarted(id) => player.quest_status(id).is_none(),
+            Condition::QuestActive(id) => player.quest_status(id) == Some(QuestStatus::Active),
+            Condition::QuestReady(id) => {
+                match (quests.iter().find(|q| &q.id == id), player.quest(id)) {
+                    (Some(quest), Some(state)) => {
+                        state.status == QuestStatus::Active && quest.is_ready(state, player)
+                    }
+                    _ => false,
+                }
+            }
+            Condition::QuestCompleted(id) => {
+                player.quest_status(id) == Some(QuestStatus::Completed)
+            }
+            Condition::Flag(flag) => player.flags.contains(flag),
+            Condition::NotFlag(flag) => !player.flags.contains(flag),
+        }
+    }
+}
+
+/// What picking a choice does, applied in order before moving on.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum DialogueEffect {
+    GiveItem(Item),
+    TakeItem { name: String, quantity: u32 },
+    GiveGold(u32),
+    /// Heal this much; zero or less heals fully.
+    Heal(i32),
+    StartQuest(String),
+    /// Hand in a ready quest: collected items are taken, rewards given.
+    CompleteQuest(String),
+    /// Recorded in the shared world state for every hunter.
+    RevealSecret(String),
+    SetFlag(String),
+}
+
+// ── Trees ────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DialogueChoice {
+    pub text: String,
+    #[serde(default)]
+    pub conditions: Vec<Condition>,
+    #[serde(default)]
+    pub effects: Vec<DialogueEffect>,
+    /// Node to go to; None ends the conversation.
+    #[serde(default)]
+    pub next: Option<String>,
+}
+
+impl DialogueChoice {
+    pub fn is_available(&self, player: &Player, world: &WorldState, quests: &[Quest]) -> bool {
+        self.conditions.iter().all(|c| c.is_met(player, world, quests))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DialogueNode {
+    pub id: String,
+    pub text: String,
+    pub choices: Vec<DialogueChoice>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dialogue {
+    /// Id of the node the conversation opens on.
+    pub start: String,
+    pub nodes: Vec<DialogueNode>,
+}
+
+impl Dialogue {
+    pub fn node(&self, id: &str) -> Option<&DialogueNode> {
+        self.nodes.iter().find(|n| n.id == id)
+    }
+}
+
+/// A conversation in progress.
+#[derive(Debug, Clone)]
+pub struct DialogueState {
+    pub npc: String,
+    pub tree: Dialogue,
+    pub node: String,
+}
+
+impl DialogueState {
+    pub fn new(npc: String, tree: Dialogue) -> Self {
+        let node = tree.start.clone();
+        Self { npc, tree, node }
+    }
+
+    pub fn current(&self) -> Option<&DialogueNode> {
+        self.tree.node(&self.node)
+    }
+
+    /// Choices on the current node the player qualifies for.
+    pub fn available_choices(
+        &self,
+        player: &Player,
+        world: &WorldState,
+        quests: &[Quest],
+    ) -> Vec<&DialogueChoice> {
+        self.current()
+            .map(|node| {
+                node.choices
+                    .iter()
+                    .filter(|c| c.is_available(player, world, quests))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
@@ -1,28 +1,95 @@
+use std::collections::BTreeMap;
 use std::fmt;
+use std::str::FromStr;
 
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-/// A dice expression like 2d6+3.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Dice {
+/// Cap on chained explosions per die, so "1d2!" can't roll forever.
+pub const MAX_EXPLOSIONS: u32 = 5;
+
+/// Caps on parsed terms, so a data file can't ask `distribution()` for
+/// more work than a frame can afford.
+pub const MAX_DICE: u32 = 20;
+pub const MAX_SIDES: u32 = 100;
+/// Cap on a parsed flat bonus, so rolling it can't overflow.
+pub const MAX_BONUS: i32 = 1000;
+/// Cap on the face multisets `keep_distribution` walks for kh/kl terms.
+const MAX_KEEP_OUTCOMES: f64 = 200_000.0;
+
+/// Which dice of a term count toward the total.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Keep {
+    All,
+    /// `kh`: keep the N highest dice.
+    Highest(u32),
+    /// `kl`: keep the N lowest dice.
+    Lowest(u32),
+    /// `adv`: roll the whole term twice, keep the better.
+    Advantage,
+    /// `dis`: roll the whole term twice, keep the worse.
+    Disadvantage,
+}
+
+/// One `NdS` group within an expression, e.g. the `4d6kh3` in "4d6kh3+2".
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiceTerm {
     pub count: u32,
     pub sides: u32,
+    pub keep: Keep,
+    /// `!`: a die showing its max is rolled again and added.
+    pub exploding: bool,
+    /// Subtracted rather than added.
+    pub negative: bool,
+}
+
+/// A dice expression like 2d6+3, 4d6kh3, 1d20adv, 2d10! or 1d8+1d4+2.
+///
+/// Serializes as its string form, so data files can write "2d6+3".
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Dice {
+    pub terms: Vec<DiceTerm>,
     pub bonus: i32,
 }
 
 impl Dice {
     pub fn new(count: u32, sides: u32, bonus: i32) -> Self {
-        Self { count, sides, bonus }
+        Self {
+            terms: vec![DiceTerm {
+                count,
+                sides,
+                keep: Keep::All,
+                exploding: false,
+                negative: false,
+            }],
+            bonus,
+        }
+    }
+
+    /// Same expression with the first term's keep rule replaced — used to
+    /// give a plain d20 advantage or disadvantage.
+    pub fn with_keep(mut self, keep: Keep) -> Self {
+        if let Some(term) = self.terms.first_mut() {
+            term.keep = keep;
+        }
+        self
     }
 
     /// Roll the dice and return (total, individual_rolls).
     pub fn roll(&self) -> DiceResult {
         let mut rng = rand::thread_rng();
-        let rolls: Vec<u32> = (0..self.count)
-            .map(|_| rng.gen_range(1..=self.sides))
-            .collect();
-        let sum: i32 = rolls.iter().map(|&r| r as i32).sum::<i32>() + self.bonus;
+        let rolls: Vec<Vec<DieRoll>> = self.terms.iter().map(|t| t.roll(&mut rng)).collect();
+        let sum: i32 = self
+            .terms
+            .iter()
+            .zip(&rolls)
+            .map(|(term, dice)| {
+                let kept: i32 = dice.iter().filter(|d| d.kept).map(|d| d.value as i32).sum();
+                if term.negative { -kept } else { kept }
+            })
+            .sum::<i32>()
+            + self.bonus;
         DiceResult {
             total: sum.max(1),
             rolls,
@@ -31,9 +98,435 @@ impl Dice {
     }
 }
 
-impl fmt::Display for Dice {
+impl DiceTerm {
+    fn roll_die(&self, rng: &mut impl Rng) -> DieRoll {
+        let mut value = rng.gen_range(1..=self.sides);
+        let mut exploded = false;
+        if self.exploding && self.sides > 1 {
+            let mut last = value;
+            let mut chain = 0;
+            while last == self.sides && chain < MAX_EXPLOSIONS {
+                last = rng.gen_range(1..=self.sides);
+                value += last;
+                exploded = true;
+                chain += 1;
+            }
+        }
+        DieRoll {
+            value,
+            exploded,
+            kept: true,
+        }
+    }
+
+    fn roll_set(&self, rng: &mut impl Rng) -> Vec<DieRoll> {
+        (0..self.count).map(|_| self.roll_die(rng)).collect()
+    }
+
+    fn roll(&self, rng: &mut impl Rng) -> Vec<DieRoll> {
+        match self.keep {
+            Keep::All => self.roll_set(rng),
+            Keep::Highest(n) | Keep::Lowest(n) => {
+                let mut dice = self.roll_set(rng);
+                let mut order: Vec<usize> = (0..dice.len()).collect();
+                order.sort_by_key(|&i| dice[i].value);
+                if matches!(self.keep, Keep::Highest(_)) {
+                    order.reverse();
+                }
+                for &i in order.iter().skip(n as usize) {
+                    dice[i].kept = false;
+                }
+                dice
+            }
+            Keep::Advantage | Keep::Disadvantage => {
+                let mut first = self.roll_set(rng);
+                let mut second = self.roll_set(rng);
+                let sum = |d: &[DieRoll]| d.iter().map(|r| r.value).sum::<u32>();
+                let first_wins = if self.keep == Keep::Advantage {
+                    sum(&first) >= sum(&second)
+                } else {
+                    sum(&first) <= sum(&second)
+                };
+                let loser = if first_wins { &mut second } else { &mut first };
+                loser.iter_mut().for_each(|d| d.kept = false);
+                if first_wins {
+                    first.extend(second);
+                    first
+                } else {
+                    second.extend(first);
+                    second
+                }
+            }
+        }
+    }
+}
+
+// ── Probability ──────────────────────────────────────────────────────
+
+/// Probability of each possible total.
+pub type Distribution = BTreeMap<i32, f64>;
+
+impl Dice {
+    /// Exact probability of every total `roll` can produce, including the
+    /// `max(1)` clamp and the explosion cap.
+    pub fn distribution(&self) -> Distribution {
+        let mut dist = Distribution::from([(self.bonus, 1.0)]);
+        for term in &self.terms {
+            let mut term_dist = term.distribution();
+            if term.negative {
+                term_dist = term_dist.into_iter().map(|(v, p)| (-v, p)).collect();
+            }
+            dist = convolve(&dist, &term_dist);
+        }
+
+        let mut clamped = Distribution::new();
+        for (total, p) in dist {
+            *clamped.entry(total.max(1)).or_insert(0.0) += p;
+        }
+        clamped
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.distribution().iter().map(|(&v, p)| v as f64 * p).sum()
+    }
+
+    pub fn min(&self) -> i32 {
+        self.distribution().keys().next().copied().unwrap_or(1)
+    }
+
+    pub fn max(&self) -> i32 {
+        self.distribution().keys().next_back().copied().unwrap_or(1)
+    }
+
+    /// Chance (0.0–1.0) that a roll totals `target` or more.
+    pub fn chance_at_least(&self, target: i32) -> f64 {
+        self.distribution().range(target..).map(|(_, p)| p).sum()
+    }
+
+    /// One-line summary for tooltips, e.g. "2d6+3: avg 10, max 15".
+    pub fn summary(&self) -> String {
+        let mean = self.mean();
+        let avg = if (mean - mean.round()).abs() < 0.05 {
+            format!("{:.0}", mean)
+        } else {
+            format!("{:.1}", mean)
+        };
+        format!("{}: avg {}, max {}", self, avg, self.max())
+    }
+}
+
+impl DiceTerm {
+    /// Outcomes of a single die, with explosions chained up to the cap.
+    fn die_faces(&self) -> Vec<(i32, f64)> {
+        let sides = self.sides as i32;
+        let p = 1.0 / self.sides as f64;
+        if !self.exploding || self.sides == 1 {
+            return (1..=sides).map(|v| (v, p)).collect();
+        }
+
+        let mut faces = Vec::new();
+        for chain in 0..=MAX_EXPLOSIONS as i32 {
+            // `chain` max rolls in a row, then a final roll that stops the
+            // chain — any face once the cap is reached
+            let last = if chain == MAX_EXPLOSIONS as i32 { sides } else { sides - 1 };
+            let p_chain = p.powi(chain + 1);
+            faces.extend((1..=last).map(|v| (chain * sides + v, p_chain)));
+        }
+        faces
+    }
+
+    /// Distribution of this term's kept sum (sign not applied).
+    fn distribution(&self) -> Distribution {
+        let faces = self.die_faces();
+        match self.keep {
+            Keep::All => self.sum_distribution(&faces),
+            Keep::Highest(n) => keep_distribution(&faces, self.count, n, true),
+            Keep::Lowest(n) => keep_distribution(&faces, self.count, n, false),
+            Keep::Advantage | Keep::Disadvantage => {
+                // Two independent rolls of the term, keep the better/worse
+                let single = self.sum_distribution(&faces);
+                let mut below = 0.0;
+                let mut dist = Distribution::new();
+                for (&v, &p) in &single {
+                    let at_or_below = below + p;
+                    let chance = if self.keep == Keep::Advantage {
+                        at_or_below * at_or_below - below * below
+                    } else {
+                        (1.0 - below).powi(2) - (1.0 - at_or_below).powi(2)
+                    };
+                    dist.insert(v, chance);
+                    below = at_or_below;
+                }
+                dist
+            }
+        }
+    }
+
+    /// How many face multisets `keep_distribution` would walk.
+    fn keep_outcomes(&self) -> f64 {
+        // C(faces + count - 1, count)
+        let faces = self.die_faces().len() as f64;
+        (1..=self.count)
+            .map(|i| (faces + i as f64 - 1.0) / i as f64)
+            .product()
+    }
+
+    fn sum_distribution(&self, faces: &[(i32, f64)]) -> Distribution {
+        let die: Distribution = faces.iter().copied().collect();
+        (0..self.count).fold(Distribution::from([(0, 1.0)]), |acc, _| convolve(&acc, &die))
+    }
+}
+
+fn convolve(a: &Distribution, b: &Distribution) -> Distribution {
+    let mut out = Distribution::new();
+    for (&va, &pa) in a {
+        for (&vb, &pb) in b {
+            *out.entry(va + vb).or_insert(0.0) += pa * pb;
+        }
+    }
+    out
+}
+
+/// Distribution of the sum of the `keep` highest (or lowest) of `count`
+/// dice. Walks every multiset of faces once, weighted by how many ordered
+/// rolls produce it, instead of every ordered roll.
+fn keep_distribution(faces: &[(i32, f64)], count: u32, keep: u32, highest: bool) -> Distribution {
+    let mut sorted = faces.to_vec();
+    sorted.sort_by_key(|&(v, _)| if highest { -v } else { v });
+
+    let mut dist = Distribution::new();
+    let mut picked = Vec::with_capacity(count as usize);
+    walk_multisets(&sorted, 0, count, 1.0, count, &mut picked, &mut |picked, p| {
+        // `picked` is in best-first order, so the kept dice are its prefix
+        let sum: i32 = picked.iter().take(keep as usize).sum();
+        *dist.entry(sum).or_insert(0.0) += p;
+    });
+    dist
+}
+
+fn walk_multisets(
+    faces: &[(i32, f64)],
+    start: usize,
+    remaining: u32,
+    p: f64,
+    total: u32,
+    picked: &mut Vec<i32>,
+    visit: &mut impl FnMut(&[i32], f64),
+) {
+    if remaining == 0 {
+        // Multinomial: orderings of this multiset among `total` dice
+        let mut ways = factorial(total);
+        let mut i = 0;
+        while i < picked.len() {
+            let run = picked[i..].iter().take_while(|&&v| v == picked[i]).count();
+            ways /= factorial(run as u32);
+            i += run;
+        }
+        visit(picked, p * ways);
+        return;
+    }
+    for (i, &(value, face_p)) in faces.iter().enumerate().skip(start) {
+        picked.push(value);
+        walk_multisets(faces, i, remaining - 1, p * face_p, total, picked, visit);
+        picked.pop();
+    }
+}
+
+fn factorial(n: u32) -> f64 {
+    (1..=n).map(|i| i as f64).product()
+}
+
+// ── Parsing ──────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseDiceError(String);
+
+impl fmt::Display for ParseDiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid dice expression: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseDiceError {}
+
+impl FromStr for Dice {
+    type Err = ParseDiceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let expr: String = s.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_lowercase();
+        if expr.is_empty() {
+            return Err(ParseDiceError("empty expression".into()));
+        }
+
+        let mut dice = Dice {
+            terms: Vec::new(),
+            bonus: 0,
+        };
+        let mut rest = expr.as_str();
+        let mut negative = false;
+
+        // Leading sign is optional on the first term
+        if let Some(r) = rest.strip_prefix('-') {
+            negative = true;
+            rest = r;
+        } else if let Some(r) = rest.strip_prefix('+') {
+            rest = r;
+        }
+
+        loop {
+            let end = rest.find(['+', '-']).unwrap_or(rest.len());
+            let (term, tail) = rest.split_at(end);
+            if term.is_empty() {
+                return Err(ParseDiceError(format!("missing term in \"{}\"", s)));
+            }
+
+            if term.contains('d') {
+                let mut parsed = parse_term(term)?;
+                parsed.negative = negative;
+                dice.terms.push(parsed);
+            } else {
+                let value: i32 = term
+                    .parse()
+                    .map_err(|_| ParseDiceError(format!("bad number \"{}\"", term)))?;
+                dice.bonus = if negative { value.checked_neg() } else { Some(value) }
+                    .and_then(|value| dice.bonus.checked_add(value))
+                    .filter(|bonus| bonus.abs() <= MAX_BONUS)
+                    .ok_or_else(|| {
+                        ParseDiceError(format!(
+                            "bonus in \"{}\" is too big (at most ±{})",
+                            s, MAX_BONUS
+                        ))
+                    })?;
+            }
+
+            if tail.is_empty() {
+                break;
+            }
+            negative = tail.starts_with('-');
+            rest = &tail[1..];
+        }
+
+        if dice.terms.is_empty() {
+            return Err(ParseDiceError(format!("no dice in \"{}\"", s)));
+        }
+        Ok(dice)
+    }
+}
+
+/// Parse a single `[N]dS[kh N|kl N|adv|dis][!]` term.
+fn parse_term(term: &str) -> Result<DiceTerm, ParseDiceError> {
+    let (count, rest) = term.split_once('d').unwrap_or(("", term));
+    let count: u32 = if count.is_empty() {
+        1
+    } else {
+        count
+            .parse()
+            .map_err(|_| ParseDiceError(format!("bad dice count in \"{}\"", term)))?
+    };
+
+    let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    let (sides, mut mods) = rest.split_at(digits);
+    let sides: u32 = sides
+        .parse()
+        .map_err(|_| ParseDiceError(format!("bad die size in \"{}\"", term)))?;
+    if count == 0 || sides == 0 {
+        return Err(ParseDiceError(format!("\"{}\" rolls no dice", term)));
+    }
+    if count > MAX_DICE || sides > MAX_SIDES {
+        return Err(ParseDiceError(format!(
+            "\"{}\" is too big (at most {}d{})",
+            term, MAX_DICE, MAX_SIDES
+        )));
+    }
+
+    let mut parsed = DiceTerm {
+        count,
+        sides,
+        keep: Keep::All,
+        exploding: false,
+        negative: false,
+    };
+
+    while !mods.is_empty() {
+        if let Some(r) = mods.strip_prefix('!') {
+            parsed.exploding = true;
+            mods = r;
+        } else if let Some(r) = mods.strip_prefix("adv") {
+            parsed.keep = Keep::Advantage;
+            mods = r;
+        } else if let Some(r) = mods.strip_prefix("dis") {
+            parsed.keep = Keep::Disadvantage;
+            mods = r;
+        } else if let Some(r) = mods.strip_prefix("kh").or_else(|| mods.strip_prefix("kl")) {
+            let digits = r.find(|c: char| !c.is_ascii_digit()).unwrap_or(r.len());
+            let n: u32 = r[..digits]
+                .parse()
+                .map_err(|_| ParseDiceError(format!("bad keep count in \"{}\"", term)))?;
+            if n == 0 || n > count {
+                return Err(ParseDiceError(format!("can't keep {} of {} in \"{}\"", n, count, term)));
+            }
+            parsed.keep = if mods.starts_with("kh") {
+                Keep::Highest(n)
+            } else {
+                Keep::Lowest(n)
+            };
+            mods = &r[digits..];
+        } else {
+            return Err(ParseDiceError(format!("unknown modifier \"{}\"", mods)));
+        }
+    }
+
+    if matches!(parsed.keep, Keep::Highest(_) | Keep::Lowest(_))
+        && parsed.keep_outcomes() > MAX_KEEP_OUTCOMES
+    {
+        return Err(ParseDiceError(format!("\"{}\" keeps from too many dice", term)));
+    }
+    Ok(parsed)
+}
+
+impl TryFrom<String> for Dice {
+    type Error = ParseDiceError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<Dice> for String {
+    fn from(dice: Dice) -> Self {
+        dice.to_string()
+    }
+}
+
+// ── Display ──────────────────────────────────────────────────────────
+
+impl fmt::Display for DiceTerm {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}d{}", self.count, self.sides)?;
+        match self.keep {
+            Keep::All => {}
+            Keep::Highest(n) => write!(f, "kh{}", n)?,
+            Keep::Lowest(n) => write!(f, "kl{}", n)?,
+            Keep::Advantage => write!(f, "adv")?,
+            Keep::Disadvantage => write!(f, "dis")?,
+        }
+        if self.exploding {
+            write!(f, "!")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Dice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, term) in self.terms.iter().enumerate() {
+            if term.negative {
+                write!(f, "-")?;
+            } else if i > 0 {
+                write!(f, "+")?;
+            }
+            write!(f, "{}", term)?;
+        }
         match self.bonus.cmp(&0) {
             std::cmp::Ordering::Greater => write!(f, "+{}", self.bonus),
             std::cmp::Ordering::Less => write!(f, "{}", self.bonus), // negative sign included
@@ -42,21 +535,193 @@ impl fmt::Display for Dice {
     }
 }
 
+/// A single die as rolled. `value` includes any explosions.
+#[derive(Debug, Clone)]
+pub struct DieRoll {
+    pub value: u32,
+    pub exploded: bool,
+    /// False for dice discarded by keep-highest/lowest or advantage.
+    pub kept: bool,
+}
+
 pub struct DiceResult {
     pub total: i32,
-    pub rolls: Vec<u32>,
+    /// Dice rolled for each term, in expression order.
+    pub rolls: Vec<Vec<DieRoll>>,
     pub dice: Dice,
 }
 
+impl DiceResult {
+    /// The first kept die of the first term — the "natural" roll on a d20.
+    pub fn natural(&self) -> u32 {
+        self.rolls
+            .first()
+            .and_then(|dice| dice.iter().find(|d| d.kept))
+            .map(|d| d.value)
+            .unwrap_or(0)
+    }
+}
+
 impl fmt::Display for DiceResult {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let rolls_str: Vec<String> = self.rolls.iter().map(|r| r.to_string()).collect();
+        let terms_str: Vec<String> = self
+            .rolls
+            .iter()
+            .map(|dice| {
+                let kept: Vec<String> = dice
+                    .iter()
+                    .filter(|d| d.kept)
+                    .map(|d| format!("{}{}", d.value, if d.exploded { "!" } else { "" }))
+                    .collect();
+                let dropped: Vec<String> = dice
+                    .iter()
+                    .filter(|d| !d.kept)
+                    .map(|d| d.value.to_string())
+                    .collect();
+                if dropped.is_empty() {
+                    kept.join("+")
+                } else {
+                    format!("{} ({})", kept.join("+"), dropped.join(","))
+                }
+            })
+            .collect();
         write!(
             f,
             "{} [{}] = {}",
             self.dice,
-            rolls_str.join("+"),
+            terms_str.join(" | "),
             self.total
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dice(s: &str) -> Dice {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn display_round_trips_through_from_str() {
+        for expr in [
+            "1d20",
+            "2d6+3",
+            "1d8-1",
+            "4d6kh3",
+            "3d6kl1",
+            "1d20adv",
+            "1d20dis",
+            "2d10!",
+            "1d8+1d4+2",
+            "2d6-1d4",
+            "-1d4+5",
+            "4d6kh3!+1",
+        ] {
+            let parsed = dice(expr);
+            assert_eq!(parsed.to_string(), expr);
+            assert_eq!(dice(&parsed.to_string()), parsed);
+        }
+    }
+
+    #[test]
+    fn parsing_normalises_case_and_spacing() {
+        assert_eq!(dice(" 2D6 + 3 ").to_string(), "2d6+3");
+        assert_eq!(dice("d20").to_string(), "1d20");
+        assert_eq!(dice("1d6+2+1").bonus, 3);
+    }
+
+    #[test]
+    fn parsing_rejects_bad_expressions() {
+        for expr in [
+            "", "5", "0d6", "2d0", "2d", "d", "2d6+", "2d6x", "2d6kh0", "2d6kh3", "1d6++1",
+        ] {
+            assert!(expr.parse::<Dice>().is_err(), "{:?} should not parse", expr);
+        }
+    }
+
+    #[test]
+    fn parsing_caps_dice_that_would_blow_up_distribution() {
+        assert!(format!("{}d6", MAX_DICE).parse::<Dice>().is_ok());
+        assert!(format!("{}d6", MAX_DICE + 1).parse::<Dice>().is_err());
+        assert!(format!("1d{}", MAX_SIDES).parse::<Dice>().is_ok());
+        assert!(format!("1d{}", MAX_SIDES + 1).parse::<Dice>().is_err());
+        assert!("20d100kh1".parse::<Dice>().is_err());
+        assert!("20d100!kh1".parse::<Dice>().is_err());
+    }
+
+    #[test]
+    fn parsing_caps_bonuses_without_overflowing() {
+        assert!(format!("1d6+{}", MAX_BONUS).parse::<Dice>().is_ok());
+        assert!(format!("1d6-{}", MAX_BONUS).parse::<Dice>().is_ok());
+        assert!(format!("1d6+{}", MAX_BONUS + 1).parse::<Dice>().is_err());
+        assert!("1d6+2147483647+1".parse::<Dice>().is_err());
+        assert!("1d6-2147483647-1".parse::<Dice>().is_err());
+        assert!("1d6+2147483647-2147483647".parse::<Dice>().is_err());
+    }
+
+    #[test]
+    fn serializes_as_its_string_form() {
+        let parsed = dice("2d6+3");
+        let json = serde_json::to_string(&parsed).unwrap();
+        assert_eq!(json, "\"2d6+3\"");
+        assert_eq!(serde_json::from_str::<Dice>(&json).unwrap(), parsed);
+        assert!(serde_json::from_str::<Dice>("\"2d0\"").is_err());
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn distributions_sum_to_one() {
+        for expr in ["2d6+3", "4d6kh3", "3d6kl1", "1d20adv", "1d20dis", "2d6!", "1d4-1d6"] {
+            assert_close(dice(expr).distribution().values().sum(), 1.0);
+        }
+    }
+
+    #[test]
+    fn plain_sums_match_by_hand() {
+        let two_d6 = dice("2d6+3");
+        assert_eq!(two_d6.min(), 5);
+        assert_eq!(two_d6.max(), 15);
+        assert_close(two_d6.mean(), 10.0);
+        assert_close(two_d6.distribution()[&10], 6.0 / 36.0);
+        assert_close(two_d6.chance_at_least(14), 3.0 / 36.0);
+    }
+
+    #[test]
+    fn totals_clamp_to_one() {
+        let dist = dice("1d4-3").distribution();
+        assert_eq!(dist.keys().copied().collect::<Vec<_>>(), vec![1]);
+        assert_close(dist[&1], 1.0);
+    }
+
+    #[test]
+    fn keep_and_advantage_match_known_means() {
+        assert_close(dice("4d6kh3").mean(), 15869.0 / 1296.0);
+        assert_close(dice("2d20kh1").mean(), dice("1d20adv").mean());
+        assert_close(dice("1d20adv").mean(), 13.825);
+        assert_close(dice("1d20dis").mean(), 7.175);
+    }
+
+    #[test]
+    fn explosions_chain_up_to_the_cap() {
+        let exploding = dice("1d6!");
+        assert_eq!(exploding.max(), 6 * (MAX_EXPLOSIONS as i32 + 1));
+        // A six never stands alone: it always rolls again
+        assert!(!exploding.distribution().contains_key(&6));
+        assert_close(exploding.distribution()[&7], 1.0 / 36.0);
+    }
+
+    #[test]
+    fn rolls_stay_in_range() {
+        let d = dice("4d6kh3+1");
+        for _ in 0..200 {
+            let result = d.roll();
+            assert!((4..=19).contains(&result.total));
+            assert_eq!(result.rolls[0].iter().filter(|r| r.kept).count(), 3);
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+/// Chosen at creation and fixed for the life of the hunter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Difficulty {
+    /// Death sends you back to the village with your gear, minus some EXP.
+    Casual,
+    /// Death sends you back to your last rest point, minus some EXP; your
+    /// gear stays on your corpse until you walk back for it.
+    CorpseRun,
+    /// Death starts the hunter over from scratch.
+    #[default]
+    Normal,
+    /// Death is final: the save goes to the graveyard.
+    Hardcore,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Casual,
+        Difficulty::CorpseRun,
+        Difficulty::Normal,
+        Difficulty::Hardcore,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Difficulty::Casual => "Casual",
+            Difficulty::CorpseRun => "Corpse Run",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hardcore => "Hardcore",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Difficulty::Casual => {
+                "Enemies hit softer. Death returns you to the village with your gear, \
+                 losing a quarter of this level's EXP."
+            }
+            Difficulty::CorpseRun => {
+                "Death returns you to your last rest point, losing a quarter of \
+                 this level's EXP. Your gear stays where you fell until you walk \
+                 back for it."
+            }
+            Difficulty::Normal => "The intended experience. Death means starting over.",
+            Difficulty::Hardcore => {
+                "Enemies hit harder but EXP comes faster. Death is permanent: \
+                 your name goes on the memorial."
+            }
+        }
+    }
+
+    /// Scale applied to damage the player takes.
+    pub fn damage_taken(&self) -> f64 {
+        match self {
+            Difficulty::Casual => 0.75,
+            Difficulty::CorpseRun | Difficulty::Normal => 1.0,
+            Difficulty::Hardcore => 1.25,
+        }
+    }
+
+    /// Scale applied to EXP the player earns.
+    pub fn exp_gained(&self) -> f64 {
+        match self {
+            Difficulty::Casual | Difficulty::CorpseRun | Difficulty::Normal => 1.0,
+            Difficulty::Hardcore => 1.5,
+        }
+    }
+
+    /// Damage after scaling; a hit always does at least 1.
+    pub fn scale_damage(&self, damage: i32) -> i32 {
+        ((damage as f64 * self.damage_taken()).round() as i32).max(1)
+    }
+
+    pub fn scale_exp(&self, exp: u32) -> u32 {
+        (exp as f64 * self.exp_gained()).round() as u32
+    }
+
+    /// The next mode in `ALL`, wrapping around.
+    pub fn next(&self) -> Difficulty {
+        let index = Self::ALL.iter().position(|d| d == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// The previous mode in `ALL`, wrapping around.
+    pub fn prev(&self) -> Difficulty {
+        let index = Self::ALL.iter().position(|d| d == self).unwrap_or(0);
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
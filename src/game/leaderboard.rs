@@ -0,0 +1,301 @@
+use super::difficulty::Difficulty;
+use super::player::Player;
+use super::world::{VictoryRecord, WorldState};
+
+/// Rows shown per board.
+const TOP: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Board {
+    Level,
+    Kills,
+    FastestVictory,
+    FewestTurns,
+    BossKills,
+    Hardcore,
+}
+
+impl Board {
+    pub const ALL: [Board; 6] = [
+        Board::Level,
+        Board::Kills,
+        Board::FastestVictory,
+        Board::FewestTurns,
+        Board::BossKills,
+        Board::Hardcore,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Board::Level => "Highest Level",
+            Board::Kills => "Most Kills",
+            Board::FastestVictory => "Fastest Victory",
+            Board::FewestTurns => "Fewest Turns",
+            Board::BossKills => "Boss Kills",
+            Board::Hardcore => "Deepest Hardcore",
+        }
+    }
+}
+
+/// One ranked hunter.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub name: String,
+    /// e.g. "level 6 Ranger".
+    pub detail: String,
+    pub score: String,
+    /// Hunter is in the graveyard.
+    pub dead: bool,
+}
+
+/// Leaderboard screen: every board, computed when the screen opens.
+#[derive(Debug, Clone)]
+pub struct LeaderboardState {
+    pub boards: Vec<(Board, Vec<Entry>)>,
+    pub tab: usize,
+}
+
+impl LeaderboardState {
+    /// Rank `living` saves, `buried` hardcore saves and the world's
+    /// victory records.
+    pub fn new(living: &[Player], buried: &[Player], world: &WorldState) -> Self {
+        let hunters: Vec<(&Player, bool)> = living
+            .iter()
+            .map(|p| (p, false))
+            .chain(buried.iter().map(|p| (p, true)))
+            .collect();
+        let boards = Board::ALL
+            .into_iter()
+            .map(|board| (board, rank(board, &hunters, world)))
+            .collect();
+        Self { boards, tab: 0 }
+    }
+
+    pub fn current(&self) -> Option<&(Board, Vec<Entry>)> {
+        self.boards.get(self.tab)
+    }
+
+    pub fn next_tab(&mut self) {
+        self.tab = (self.tab + 1) % self.boards.len().max(1);
+    }
+
+    pub fn prev_tab(&mut self) {
+        let count = self.boards.len().max(1);
+        self.tab = (self.tab + count - 1) % count;
+    }
+}
+
+fn rank(board: Board, hunters: &[(&Player, bool)], world: &WorldState) -> Vec<Entry> {
+    match board {
+        Board::Level => top_by(hunters, |p| Some((p.level, p.exp)), |p| {
+            format!("Lv.{}", p.level)
+        }),
+        Board::Kills => top_by(
+            hunters,
+            |p| Some(p.stats.total_kills()).filter(|&k| k > 0),
+            |p| format!("{} kills", p.stats.total_kills()),
+        ),
+        Board::BossKills => top_by(
+            hunters,
+            |p| Some(p.stats.boss_kills).filter(|&k| k > 0),
+            |p| format!("{} bosses", p.stats.boss_kills),
+        ),
+        Board::Hardcore => top_by(
+            hunters,
+            |p| (p.difficulty == Difficulty::Hardcore).then_some((p.level, p.exp)),
+            |p| format!("Lv.{}", p.level),
+        ),
+        Board::FastestVictory => fastest(world, |v| v.seconds, format_duration),
+        Board::FewestTurns => fastest(world, |v| v.turns.map(i64::from), |turns| {
+            format!("{} turns", turns)
+        }),
+    }
+}
+
+/// The top hunters by `key`, highest first, skipping those it rejects.
+fn top_by<K: Ord>(
+    hunters: &[(&Player, bool)],
+    key: impl Fn(&Player) -> Option<K>,
+    score: impl Fn(&Player) -> String,
+) -> Vec<Entry> {
+    let mut ranked: Vec<(K, &Player, bool)> = hunters
+        .iter()
+        .filter_map(|&(p, dead)| key(p).map(|k| (k, p, dead)))
+        .collect();
+    ranked.sort_by(|a, b| b.0.cmp(&a.0));
+    ranked
+        .into_iter()
+        .take(TOP)
+        .map(|(_, p, dead)| Entry {
+            name: p.name.clone(),
+            detail: format!("level {} {}", p.level, p.class_label()),
+            score: score(p),
+            dead,
+        })
+        .collect()
+}
+
+/// Victories with the lowest `key`, skipping those recorded without one.
+fn fastest(
+    world: &WorldState,
+    key: impl Fn(&VictoryRecord) -> Option<i64>,
+    score: impl Fn(i64) -> String,
+) -> Vec<Entry> {
+    let mut ranked: Vec<(i64, &VictoryRecord)> = world
+        .victories
+        .iter()
+        .filter_map(|v| key(v).map(|k| (k, v)))
+        .collect();
+    ranked.sort_by_key(|(k, _)| *k);
+    ranked
+        .into_iter()
+        .take(TOP)
+        .map(|(k, v)| Entry {
+            name: v.name.clone(),
+            detail: format!("level {} {} ({})", v.level, v.class, v.difficulty.label()),
+            score: score(k),
+            dead: false,
+        })
+        .collect()
+}
+
+/// "1h 05m 12s", dropping leading zero units.
+pub fn format_duration(seconds: i64) -> String {
+    let (h, m, s) = (seconds / 3600, seconds % 3600 / 60, seconds % 60);
+    if h > 0 {
+        format!("{}h {:02}m {:02}s", h, m, s)
+    } else if m > 0 {
+        format!("{}m {:02}s", m, s)
+    } else {
+        format!("{}s", s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::progression::Progression;
+
+    fn hunter(name: &str, level: u32, exp: u32) -> Player {
+        let mut player = Player::new(name.into(), &Progression::default());
+        player.level = level;
+        player.exp = exp;
+        player
+    }
+
+    fn victory(name: &str, seconds: Option<i64>, turns: Option<u32>) -> VictoryRecord {
+        VictoryRecord {
+            name: name.into(),
+            level: 5,
+            class: "Warrior".into(),
+            difficulty: Difficulty::Normal,
+            at: chrono::Utc::now(),
+            seconds,
+            turns,
+        }
+    }
+
+    fn names(board: &LeaderboardState, which: Board) -> Vec<(String, bool)> {
+        board
+            .boards
+            .iter()
+            .find(|(b, _)| *b == which)
+            .unwrap()
+            .1
+            .iter()
+            .map(|e| (e.name.clone(), e.dead))
+            .collect()
+    }
+
+    fn owned(list: &[(&str, bool)]) -> Vec<(String, bool)> {
+        list.iter().map(|&(n, d)| (n.to_string(), d)).collect()
+    }
+
+    #[test]
+    fn level_board_ranks_by_level_then_exp_and_marks_the_dead() {
+        let living = [hunter("Ash", 3, 10), hunter("Birch", 5, 0), hunter("Cedar", 3, 40)];
+        let buried = [hunter("Dusk", 4, 0)];
+        let board = LeaderboardState::new(&living, &buried, &WorldState::default());
+        assert_eq!(
+            names(&board, Board::Level),
+            owned(&[("Birch", false), ("Dusk", true), ("Cedar", false), ("Ash", false)])
+        );
+    }
+
+    #[test]
+    fn boards_keep_only_the_top_entries() {
+        let living: Vec<Player> = (0..TOP as u32 + 5)
+            .map(|i| hunter(&format!("H{}", i), i + 1, 0))
+            .collect();
+        let board = LeaderboardState::new(&living, &[], &WorldState::default());
+        let level = names(&board, Board::Level);
+        assert_eq!(level.len(), TOP);
+        assert_eq!(level[0].0, format!("H{}", TOP + 4));
+    }
+
+    #[test]
+    fn kill_boards_skip_hunters_with_none() {
+        let mut ash = hunter("Ash", 1, 0);
+        ash.stats.record_kill("Wolf", false);
+        ash.stats.record_kill("Wolf", false);
+        let mut birch = hunter("Birch", 1, 0);
+        birch.stats.record_kill("Cave Troll", true);
+        let idle = hunter("Cedar", 9, 0);
+
+        let board = LeaderboardState::new(&[ash, birch, idle], &[], &WorldState::default());
+        assert_eq!(names(&board, Board::Kills), owned(&[("Ash", false), ("Birch", false)]));
+        assert_eq!(names(&board, Board::BossKills), owned(&[("Birch", false)]));
+    }
+
+    #[test]
+    fn hardcore_board_only_counts_hardcore_hunters() {
+        let mut ash = hunter("Ash", 2, 0);
+        ash.difficulty = Difficulty::Hardcore;
+        let mut birch = hunter("Birch", 7, 0);
+        birch.difficulty = Difficulty::Hardcore;
+        let casual = hunter("Cedar", 9, 0);
+
+        let board = LeaderboardState::new(&[ash, casual], &[birch], &WorldState::default());
+        assert_eq!(
+            names(&board, Board::Hardcore),
+            owned(&[("Birch", true), ("Ash", false)])
+        );
+    }
+
+    #[test]
+    fn victory_boards_rank_lowest_first_and_skip_untimed_wins() {
+        let world = WorldState {
+            victories: vec![
+                victory("Ash", Some(900), Some(300)),
+                victory("Birch", Some(600), None),
+                victory("Cedar", None, Some(200)),
+            ],
+            ..Default::default()
+        };
+        let board = LeaderboardState::new(&[], &[], &world);
+        assert_eq!(
+            names(&board, Board::FastestVictory),
+            owned(&[("Birch", false), ("Ash", false)])
+        );
+        assert_eq!(
+            names(&board, Board::FewestTurns),
+            owned(&[("Cedar", false), ("Ash", false)])
+        );
+    }
+
+    #[test]
+    fn tabs_wrap_both_ways() {
+        let mut board = LeaderboardState::new(&[], &[], &WorldState::default());
+        board.prev_tab();
+        assert_eq!(board.current().unwrap().0, Board::Hardcore);
+        board.next_tab();
+        assert_eq!(board.current().unwrap().0, Board::Level);
+    }
+
+    #[test]
+    fn durations_drop_leading_zero_units() {
+        assert_eq!(format_duration(42), "42s");
+        assert_eq!(format_duration(125), "2m 05s");
+        assert_eq!(format_duration(3725), "1h 02m 05s");
+    }
+}
@@ -0,0 +1,209 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+
+use super::affix;
+use super::player::Item;
+
+// ── Rarity ───────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+pub enum Rarity {
+    #[default]
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+impl Rarity {
+    pub const ALL: [Rarity; 5] = [
+        Rarity::Common,
+        Rarity::Uncommon,
+        Rarity::Rare,
+        Rarity::Epic,
+        Rarity::Legendary,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Rarity::Common => "Common",
+            Rarity::Uncommon => "Uncommon",
+            Rarity::Rare => "Rare",
+            Rarity::Epic => "Epic",
+            Rarity::Legendary => "Legendary",
+        }
+    }
+
+    /// One tier up, topping out at Legendary.
+    pub fn upgraded(&self) -> Rarity {
+        match self {
+            Rarity::Common => Rarity::Uncommon,
+            Rarity::Uncommon => Rarity::Rare,
+            Rarity::Rare => Rarity::Epic,
+            Rarity::Epic | Rarity::Legendary => Rarity::Legendary,
+        }
+    }
+
+    pub fn from_label(label: &str) -> Option<Rarity> {
+        Rarity::ALL.into_iter().find(|r| r.label() == label)
+    }
+
+    /// Wrap `text` in log markup the UI colors by rarity: `{Rare|Bone Shield}`.
+    /// Common text is left plain.
+    pub fn markup(&self, text: &str) -> String {
+        match self {
+            Rarity::Common => text.to_string(),
+            _ => format!("{{{}|{}}}", self.label(), text),
+        }
+    }
+}
+
+// ── Loot tables ──────────────────────────────────────────────────────
+
+/// What a single loot entry yields.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum LootDrop {
+    Nothing,
+    /// A stack of between `min` and `max` of the item. With `affixes` set,
+    /// each copy is run through the affix generator.
+    Item {
+        item: Item,
+        #[serde(default = "one")]
+        min: u32,
+        #[serde(default = "one")]
+        max: u32,
+        #[serde(default)]
+        affixes: bool,
+    },
+    /// Roll a nested table instead.
+    Table(Box<LootTable>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LootEntry {
+    pub weight: u32,
+    pub drop: LootDrop,
+}
+
+impl LootEntry {
+    pub fn item(item: Item, weight: u32) -> Self {
+        Self::stack(item, 1, 1, weight)
+    }
+
+    pub fn stack(item: Item, min: u32, max: u32, weight: u32) -> Self {
+        Self {
+            weight,
+            drop: LootDrop::Item {
+                item,
+                min,
+                max,
+                affixes: false,
+            },
+        }
+    }
+
+    /// Gear that drops with randomly rolled affixes.
+    pub fn generated(item: Item, weight: u32) -> Self {
+        Self {
+            weight,
+            drop: LootDrop::Item {
+                item,
+                min: 1,
+                max: 1,
+                affixes: true,
+            },
+        }
+    }
+
+    pub fn table(table: LootTable, weight: u32) -> Self {
+        Self {
+            weight,
+            drop: LootDrop::Table(Box::new(table)),
+        }
+    }
+
+    pub fn nothing(weight: u32) -> Self {
+        Self {
+            weight,
+            drop: LootDrop::Nothing,
+        }
+    }
+}
+
+/// Weighted drops. Each roll picks one entry by weight; the whole table
+/// only drops at all with probability `chance`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LootTable {
+    /// Chance (0.0–1.0) that the table drops anything.
+    #[serde(default = "always")]
+    pub chance: f64,
+    /// Number of entries picked when it does.
+    #[serde(default = "one")]
+    pub rolls: u32,
+    pub entries: Vec<LootEntry>,
+}
+
+fn one() -> u32 {
+    1
+}
+
+fn always() -> f64 {
+    1.0
+}
+
+impl LootTable {
+    pub fn new(chance: f64, entries: Vec<LootEntry>) -> Self {
+        Self {
+            chance,
+            rolls: 1,
+            entries,
+        }
+    }
+
+    /// A table that always drops exactly this item.
+    pub fn guaranteed(item: Item) -> Self {
+        Self::new(1.0, vec![LootEntry::item(item, 1)])
+    }
+
+    pub fn roll(&self) -> Vec<Item> {
+        let mut rng = rand::thread_rng();
+        let mut drops = Vec::new();
+        if rng.r#gen::<f64>() >= self.chance {
+            return drops;
+        }
+        for _ in 0..self.rolls {
+            let Ok(entry) = self.entries.choose_weighted(&mut rng, |e| e.weight) else {
+                break;
+            };
+            match &entry.drop {
+                LootDrop::Nothing => {}
+                LootDrop::Item {
+                    item,
+                    min,
+                    max,
+                    affixes,
+                } => {
+                    let quantity = rng.gen_range(*min..=(*max).max(*min));
+                    if item.is_stackable() && quantity > 0 {
+                        drops.push(Item {
+                            quantity,
+                            ..item.clone()
+                        });
+                    } else {
+                        drops.extend((0..quantity).map(|_| {
+                            let copy = Item {
+                                quantity: 1,
+                                ..item.clone()
+                            };
+                            if *affixes { affix::generate(&copy) } else { copy }
+                        }));
+                    }
+                }
+                LootDrop::Table(table) => drops.extend(table.roll()),
+            }
+        }
+        drops
+    }
+}
@@ -2,12 +2,17 @@ use std::fs;
 use std::path::Path;
 
 use anyhow::{Context, Result};
+use chrono::Utc;
 
 use super::player::Player;
-use super::world::WorldState;
+use super::progression::Progression;
+use super::world::{WorldPack, WorldState};
 
 const PLAYERS_DIR: &str = "data/players";
+const GRAVEYARD_DIR: &str = "data/graveyard";
 const WORLD_STATE_FILE: &str = "data/world_state.json";
+const WORLD_PACK_FILE: &str = "data/world_pack.json";
+const PROGRESSION_FILE: &str = "data/progression.json";
 
 /// Ensure data directories exist.
 pub fn init_dirs() -> Result<()> {
@@ -34,6 +39,50 @@ pub fn load_player(name: &str) -> Result<Option<Player>> {
     Ok(Some(player))
 }
 
+/// Move a dead hardcore hunter's save out of `data/players`, keeping it
+/// under a timestamped name in the graveyard.
+pub fn archive_player(player: &Player) -> Result<()> {
+    fs::create_dir_all(GRAVEYARD_DIR).context("Failed to create graveyard directory")?;
+    let name = sanitize_name(&player.name);
+    let path = format!(
+        "{}/{}-{}.json",
+        GRAVEYARD_DIR,
+        name,
+        Utc::now().format("%Y%m%d%H%M%S")
+    );
+    let json = serde_json::to_string_pretty(player).context("Failed to serialize player")?;
+    atomic_write(&path, &json)?;
+
+    let save = format!("{}/{}.json", PLAYERS_DIR, name);
+    if Path::new(&save).exists() {
+        fs::remove_file(&save).context("Failed to remove player file")?;
+    }
+    Ok(())
+}
+
+/// Every hunter with a save, skipping files that fail to parse.
+pub fn load_all_players() -> Vec<Player> {
+    load_dir(PLAYERS_DIR)
+}
+
+/// Every hardcore hunter archived by `archive_player`.
+pub fn load_graveyard() -> Vec<Player> {
+    load_dir(GRAVEYARD_DIR)
+}
+
+fn load_dir(dir: &str) -> Vec<Player> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .filter_map(|json| serde_json::from_str(&json).ok())
+        .collect()
+}
+
 // ── World state persistence ──────────────────────────────────────────
 
 pub fn save_world_state(state: &WorldState) -> Result<()> {
@@ -59,6 +108,56 @@ pub fn reload_world_state() -> Result<WorldState> {
     load_world_state()
 }
 
+/// Reload the shared world state into `state`, apply `change` and save it
+/// back, so writes other sessions made since `state` was loaded survive.
+/// If the file can't be read, `change` still applies to `state` but nothing
+/// is written over it.
+pub fn update_world_state<T>(
+    state: &mut WorldState,
+    change: impl FnOnce(&mut WorldState) -> T,
+) -> T {
+    let reloaded = match reload_world_state() {
+        Ok(fresh) => {
+            *state = fresh;
+            true
+        }
+        Err(_) => false,
+    };
+    let result = change(state);
+    if reloaded {
+        let _ = save_world_state(state);
+    }
+    result
+}
+
+// ── World pack ───────────────────────────────────────────────────────
+
+/// Load the installed world pack, or the built-in world if none exists.
+pub fn load_world_pack() -> Result<WorldPack> {
+    if !Path::new(WORLD_PACK_FILE).exists() {
+        return Ok(WorldPack::builtin());
+    }
+    let json = fs::read_to_string(WORLD_PACK_FILE)
+        .with_context(|| format!("Failed to read {}", WORLD_PACK_FILE))?;
+    let pack: WorldPack = serde_json::from_str(&json)
+        .with_context(|| format!("Failed to parse {}", WORLD_PACK_FILE))?;
+    Ok(pack)
+}
+
+// ── Progression ──────────────────────────────────────────────────────
+
+/// Load the leveling curve, or the default one if none is installed.
+pub fn load_progression() -> Result<Progression> {
+    if !Path::new(PROGRESSION_FILE).exists() {
+        return Ok(Progression::default());
+    }
+    let json = fs::read_to_string(PROGRESSION_FILE)
+        .with_context(|| format!("Failed to read {}", PROGRESSION_FILE))?;
+    let progression: Progression = serde_json::from_str(&json)
+        .with_context(|| format!("Failed to parse {}", PROGRESSION_FILE))?;
+    Ok(progression)
+}
+
 // ── Helpers ──────────────────────────────────────────────────────────
 
 /// Atomic write: write to a temp file, then rename. Prevents corruption
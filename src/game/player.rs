@@ -1,13 +1,135 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+use super::abilities::Ability;
+use super::affix::{Affix, AffixKind};
+use super::class::ClassStats;
+use super::difficulty::Difficulty;
+use super::loot::Rarity;
+use super::progression::{LevelUp, Progression};
+use super::quest::{QuestState, QuestStatus};
+use super::stats::Stats;
+use super::status::Affliction;
+use super::talents::{Attribute, Attributes, Talent, TalentBonus};
+use super::world::{DepletedEvent, Respawn};
+
+/// Bag slots available; equipped items don't count against it.
+pub const BAG_CAPACITY: usize = 12;
+
+/// Declaration order doubles as the inventory sort order.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Default)]
 pub enum ItemType {
     Weapon,
+    Armor,
+    Trinket,
     Potion,
     Key,
+    #[default]
     Misc,
 }
 
+impl ItemType {
+    pub const ALL: [ItemType; 6] = [
+        ItemType::Weapon,
+        ItemType::Armor,
+        ItemType::Trinket,
+        ItemType::Potion,
+        ItemType::Key,
+        ItemType::Misc,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ItemType::Weapon => "Weapon",
+            ItemType::Armor => "Armor",
+            ItemType::Trinket => "Trinket",
+            ItemType::Potion => "Potion",
+            ItemType::Key => "Key",
+            ItemType::Misc => "Misc",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ItemAction {
+    Use,
+    Equip,
+    Drop,
+    Inspect,
+}
+
+impl ItemAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ItemAction::Use => "Use",
+            ItemAction::Equip => "Equip",
+            ItemAction::Drop => "Drop",
+            ItemAction::Inspect => "Inspect",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EquipSlot {
+    MainHand,
+    OffHand,
+    Head,
+    Body,
+    Trinket,
+}
+
+impl EquipSlot {
+    pub const ALL: [EquipSlot; 5] = [
+        EquipSlot::MainHand,
+        EquipSlot::OffHand,
+        EquipSlot::Head,
+        EquipSlot::Body,
+        EquipSlot::Trinket,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EquipSlot::MainHand => "Main hand",
+            EquipSlot::OffHand => "Off hand",
+            EquipSlot::Head => "Head",
+            EquipSlot::Body => "Body",
+            EquipSlot::Trinket => "Trinket",
+        }
+    }
+}
+
+/// Flat stat bonuses granted by equipped items.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatBonus {
+    pub attack: i32,
+    pub defense: i32,
+    pub max_hp: i32,
+}
+
+impl StatBonus {
+    /// Compact stat line like "+3 atk, +1 def".
+    pub fn summary(&self) -> String {
+        let parts: Vec<String> = [
+            (self.attack, "atk"),
+            (self.defense, "def"),
+            (self.max_hp, "max HP"),
+        ]
+        .iter()
+        .filter(|(v, _)| *v != 0)
+        .map(|(v, label)| format!("{:+} {}", v, label))
+        .collect();
+        parts.join(", ")
+    }
+}
+
+impl std::ops::AddAssign for StatBonus {
+    fn add_assign(&mut self, other: Self) {
+        self.attack += other.attack;
+        self.defense += other.defense;
+        self.max_hp += other.max_hp;
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Item {
     pub name: String,
@@ -15,6 +137,235 @@ pub struct Item {
     pub item_type: ItemType,
     /// Context-dependent value: heal amount for potions, damage for weapons, etc.
     pub value: i32,
+    /// Status effect carried by the item: applied to the drinker for
+    /// potions, to the enemy on hit for weapons.
+    #[serde(default)]
+    pub effect: Option<Affliction>,
+    /// Where the item is worn. Weapons and trinkets default to their
+    /// obvious slot; armor must name one.
+    #[serde(default)]
+    pub slot: Option<EquipSlot>,
+    /// Extra stats while equipped, on top of `value`.
+    #[serde(default)]
+    pub bonus: StatBonus,
+    /// Stack size. Only potions and misc items ever exceed one.
+    #[serde(default = "default_quantity")]
+    pub quantity: u32,
+    /// Gold a merchant charges for one; they buy it back for less. Zero
+    /// means nobody will trade for it.
+    #[serde(default)]
+    pub price: u32,
+    #[serde(default)]
+    pub rarity: Rarity,
+    /// Rolled prefixes and suffixes; already part of `name`.
+    #[serde(default)]
+    pub affixes: Vec<Affix>,
+}
+
+fn default_quantity() -> u32 {
+    1
+}
+
+impl Default for Item {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            description: String::new(),
+            item_type: ItemType::default(),
+            value: 0,
+            effect: None,
+            slot: None,
+            bonus: StatBonus::default(),
+            quantity: default_quantity(),
+            price: 0,
+            rarity: Rarity::Common,
+            affixes: Vec::new(),
+        }
+    }
+}
+
+impl Item {
+    /// Consumables and junk stack; gear and keys each take a slot.
+    pub fn is_stackable(&self) -> bool {
+        matches!(self.item_type, ItemType::Potion | ItemType::Misc)
+    }
+
+    fn stacks_with(&self, other: &Item) -> bool {
+        self.is_stackable() && self.item_type == other.item_type && self.name == other.name
+    }
+
+    /// Name with the stack size, e.g. "Forest Herb ×3".
+    pub fn display_name(&self) -> String {
+        if self.quantity > 1 {
+            format!("{} ×{}", self.name, self.quantity)
+        } else {
+            self.name.clone()
+        }
+    }
+
+    /// Name without rolled affixes: "Keen Bone Shield of the Bear" is
+    /// still a "Bone Shield" to a recipe.
+    pub fn base_name(&self) -> &str {
+        let mut name = self.name.as_str();
+        for affix in &self.affixes {
+            name = match affix.kind {
+                AffixKind::Prefix => name
+                    .strip_prefix(affix.name.as_str())
+                    .map(str::trim_start)
+                    .unwrap_or(name),
+                AffixKind::Suffix => name
+                    .strip_suffix(affix.name.as_str())
+                    .map(str::trim_end)
+                    .unwrap_or(name),
+            };
+        }
+        name
+    }
+
+    /// Name wrapped in rarity markup for the message log.
+    pub fn log_name(&self) -> String {
+        self.rarity.markup(&self.display_name())
+    }
+
+    pub fn equip_slot(&self) -> Option<EquipSlot> {
+        self.slot.or(match self.item_type {
+            ItemType::Weapon => Some(EquipSlot::MainHand),
+            ItemType::Trinket => Some(EquipSlot::Trinket),
+            _ => None,
+        })
+    }
+
+    /// Stats granted while equipped: `value` counts as attack for weapons
+    /// and defense for armor, plus any rolled affixes.
+    pub fn stat_bonus(&self) -> StatBonus {
+        let mut bonus = self.bonus;
+        for affix in &self.affixes {
+            bonus += affix.bonus;
+        }
+        match self.item_type {
+            ItemType::Weapon => bonus.attack += self.value,
+            ItemType::Armor => bonus.defense += self.value,
+            _ => {}
+        }
+        bonus
+    }
+
+    /// Actions the inventory screen offers for this item.
+    pub fn actions(&self) -> Vec<ItemAction> {
+        let mut actions = Vec::new();
+        if self.item_type == ItemType::Potion {
+            actions.push(ItemAction::Use);
+        }
+        if self.equip_slot().is_some() {
+            actions.push(ItemAction::Equip);
+        }
+        if self.item_type != ItemType::Key {
+            actions.push(ItemAction::Drop);
+        }
+        actions.push(ItemAction::Inspect);
+        actions
+    }
+
+    /// Full detail lines for the inspect view.
+    pub fn details(&self) -> Vec<String> {
+        let mut lines = vec![self.description.clone(), String::new()];
+        lines.push(format!(
+            "Type: {} {}",
+            self.rarity.label(),
+            self.item_type.label()
+        ));
+        if self.quantity > 1 {
+            lines.push(format!("Quantity: {}", self.quantity));
+        }
+        if self.item_type == ItemType::Potion {
+            lines.push(format!("Restores {} HP", self.value));
+        }
+        if self.price > 0 {
+            lines.push(format!("Worth: {} gold", self.price));
+        }
+        if let Some(slot) = self.equip_slot() {
+            lines.push(format!("Slot: {}", slot.label()));
+        }
+        let stats = self.stat_summary();
+        if !stats.is_empty() {
+            lines.push(format!("Stats: {}", stats));
+        }
+        for affix in &self.affixes {
+            let kind = match affix.kind {
+                AffixKind::Prefix => "prefix",
+                AffixKind::Suffix => "suffix",
+            };
+            lines.push(format!(
+                "  {} ({}): {}",
+                affix.name,
+                kind,
+                affix.bonus.summary()
+            ));
+        }
+        if let Some(affliction) = &self.effect {
+            let when = if self.item_type == ItemType::Potion {
+                "On use"
+            } else {
+                "On hit"
+            };
+            lines.push(format!(
+                "{}: {:.0}% {}",
+                when,
+                affliction.chance * 100.0,
+                affliction.effect
+            ));
+        }
+        lines
+    }
+
+    /// Compact stat line like "+3 atk, +1 def".
+    pub fn stat_summary(&self) -> String {
+        self.stat_bonus().summary()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Equipment {
+    pub main_hand: Option<Item>,
+    pub off_hand: Option<Item>,
+    pub head: Option<Item>,
+    pub body: Option<Item>,
+    pub trinket: Option<Item>,
+}
+
+impl Equipment {
+    pub fn get(&self, slot: EquipSlot) -> Option<&Item> {
+        match slot {
+            EquipSlot::MainHand => self.main_hand.as_ref(),
+            EquipSlot::OffHand => self.off_hand.as_ref(),
+            EquipSlot::Head => self.head.as_ref(),
+            EquipSlot::Body => self.body.as_ref(),
+            EquipSlot::Trinket => self.trinket.as_ref(),
+        }
+    }
+
+    fn slot_mut(&mut self, slot: EquipSlot) -> &mut Option<Item> {
+        match slot {
+            EquipSlot::MainHand => &mut self.main_hand,
+            EquipSlot::OffHand => &mut self.off_hand,
+            EquipSlot::Head => &mut self.head,
+            EquipSlot::Body => &mut self.body,
+            EquipSlot::Trinket => &mut self.trinket,
+        }
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = &Item> {
+        EquipSlot::ALL.into_iter().filter_map(|slot| self.get(slot))
+    }
+
+    pub fn total_bonus(&self) -> StatBonus {
+        let mut total = StatBonus::default();
+        for item in self.items() {
+            total += item.stat_bonus();
+        }
+        total
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,35 +379,125 @@ pub struct Player {
     pub attack: i32,
     pub defense: i32,
     pub inventory: Vec<Item>,
+    #[serde(default)]
+    pub equipment: Equipment,
     pub current_tile: usize,
+    #[serde(default = "default_stamina")]
+    pub stamina: i32,
+    #[serde(default = "default_stamina")]
+    pub max_stamina: i32,
+    #[serde(default)]
+    pub abilities: Vec<Ability>,
+    /// Tile events this hunter has used up, until they respawn.
+    #[serde(default)]
+    pub depleted: Vec<DepletedEvent>,
+    #[serde(default)]
+    pub gold: u32,
+    /// Crafting recipes learned, by output name.
+    #[serde(default)]
+    pub known_recipes: Vec<String>,
+    #[serde(default)]
+    pub quests: Vec<QuestState>,
+    /// Story markers set by dialogue, e.g. "elder_gift".
+    #[serde(default)]
+    pub flags: Vec<String>,
+    /// Class chosen at creation; empty for hunters from before classes.
+    #[serde(default)]
+    pub class: String,
+    /// Stats gained per level, copied from the class.
+    #[serde(default = "default_growth")]
+    pub growth: ClassStats,
+    #[serde(default)]
+    pub attributes: Attributes,
+    /// Unspent attribute points.
+    #[serde(default)]
+    pub attribute_points: u32,
+    #[serde(default)]
+    pub talents: Vec<Talent>,
+    /// Unspent talent points.
+    #[serde(default)]
+    pub talent_points: u32,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    /// Tile of the last rest point used; where Corpse Run hunters wake after
+    /// dying.
+    #[serde(default)]
+    pub last_rest: usize,
+    #[serde(default)]
+    pub stats: Stats,
+}
+
+fn default_stamina() -> i32 {
+    10
+}
+
+fn default_growth() -> ClassStats {
+    ClassStats {
+        max_hp: 5,
+        attack: 2,
+        defense: 1,
+        stamina: 1,
+    }
 }
 
 impl Player {
-    pub fn new(name: String) -> Self {
+    pub fn new(name: String, progression: &Progression) -> Self {
         Self {
             name,
             hp: 30,
             max_hp: 30,
             level: 1,
             exp: 0,
-            exp_to_next: 50,
+            exp_to_next: progression.exp_to_next(1),
             attack: 5,
             defense: 2,
-            inventory: vec![
-                Item {
+            inventory: vec![Item {
+                name: "Small Potion".into(),
+                description: "Restores 10 HP.".into(),
+                item_type: ItemType::Potion,
+                value: 10,
+                price: 10,
+                ..Default::default()
+            }],
+            equipment: Equipment {
+                main_hand: Some(Item {
                     name: "Rusty Sword".into(),
                     description: "A worn but serviceable blade.".into(),
                     item_type: ItemType::Weapon,
                     value: 3,
-                },
-                Item {
-                    name: "Small Potion".into(),
-                    description: "Restores 10 HP.".into(),
-                    item_type: ItemType::Potion,
-                    value: 10,
-                },
-            ],
+                    price: 5,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
             current_tile: 0,
+            stamina: default_stamina(),
+            max_stamina: default_stamina(),
+            abilities: Vec::new(),
+            depleted: Vec::new(),
+            gold: 0,
+            known_recipes: Vec::new(),
+            quests: Vec::new(),
+            flags: Vec::new(),
+            class: String::new(),
+            growth: default_growth(),
+            attributes: Attributes::default(),
+            attribute_points: 0,
+            talents: Vec::new(),
+            talent_points: 0,
+            difficulty: Difficulty::default(),
+            last_rest: 0,
+            stats: Stats::default(),
+        }
+    }
+
+    /// Class name for display; hunters from before classes are plain
+    /// hunters.
+    pub fn class_label(&self) -> &str {
+        if self.class.is_empty() {
+            "Hunter"
+        } else {
+            &self.class
         }
     }
 
@@ -65,68 +506,585 @@ impl Player {
     }
 
     pub fn heal(&mut self, amount: i32) {
-        self.hp = (self.hp + amount).min(self.max_hp);
+        self.hp = (self.hp + amount).min(self.total_max_hp());
     }
 
     #[allow(dead_code)]
     pub fn take_damage(&mut self, amount: i32) {
-        let reduced = (amount - self.defense).max(1);
+        let reduced = (amount - self.total_defense()).max(1);
         self.hp = (self.hp - reduced).max(0);
     }
 
-    pub fn gain_exp(&mut self, amount: u32) -> bool {
+    /// Add EXP and take every level-up it pays for. At the level cap EXP
+    /// no longer accumulates.
+    pub fn gain_exp(&mut self, amount: u32, progression: &Progression) -> Vec<LevelUp> {
         self.exp += amount;
-        if self.exp >= self.exp_to_next {
-            self.level_up();
-            true
-        } else {
-            false
+        self.exp_to_next = progression.exp_to_next(self.level);
+        let mut gained = Vec::new();
+        while self.exp_to_next > 0 && self.exp >= self.exp_to_next {
+            gained.push(self.level_up(progression));
         }
+        if self.exp_to_next == 0 {
+            self.exp = 0;
+        }
+        gained
     }
 
-    fn level_up(&mut self) {
+    fn level_up(&mut self, progression: &Progression) -> LevelUp {
         self.exp -= self.exp_to_next;
         self.level += 1;
-        self.exp_to_next = 50 * self.level;
-        self.max_hp += 5;
-        self.hp = self.max_hp;
-        self.attack += 2;
-        self.defense += 1;
+        self.exp_to_next = progression.exp_to_next(self.level);
+
+        let mut gains = self.growth;
+        gains += progression.bonus_at(self.level);
+        self.max_hp += gains.max_hp;
+        self.hp = self.total_max_hp();
+        self.attack += gains.attack;
+        self.defense += gains.defense;
+        self.max_stamina += gains.stamina;
+        self.stamina = self.max_stamina;
+        self.attribute_points += progression.attribute_points;
+        self.talent_points += progression.talent_points;
+        LevelUp {
+            level: self.level,
+            gains,
+            attribute_points: progression.attribute_points,
+            talent_points: progression.talent_points,
+        }
     }
 
-    pub fn best_weapon_damage(&self) -> i32 {
-        self.inventory
-            .iter()
-            .filter(|i| i.item_type == ItemType::Weapon)
-            .map(|i| i.value)
-            .max()
-            .unwrap_or(0)
+    // ── Attributes & talents ─────────────────────────────────────────
+
+    pub fn talent_bonus(&self) -> TalentBonus {
+        let mut total = TalentBonus::default();
+        for talent in &self.talents {
+            total += talent.bonus();
+        }
+        total
+    }
+
+    pub fn spend_attribute(&mut self, attribute: Attribute) -> Result<String, String> {
+        if self.attribute_points == 0 {
+            return Err("You have no attribute points to spend.".into());
+        }
+        self.attribute_points -= 1;
+        self.attributes.raise(attribute);
+        Ok(format!(
+            "{} rises to {}.",
+            attribute.label(),
+            self.attributes.get(attribute)
+        ))
+    }
+
+    pub fn learn_talent(&mut self, talent: Talent) -> Result<String, String> {
+        if self.talents.contains(&talent) {
+            return Err(format!("You already know {}.", talent.label()));
+        }
+        if let Some(required) = talent.requires()
+            && !self.talents.contains(&required)
+        {
+            return Err(format!("{} needs {} first.", talent.label(), required.label()));
+        }
+        if self.talent_points == 0 {
+            return Err("You have no talent points to spend.".into());
+        }
+        self.talent_points -= 1;
+        self.talents.push(talent);
+        Ok(format!("You learn {}: {}", talent.label(), talent.description()))
+    }
+
+    /// Refund every attribute and talent point earned so far.
+    pub fn respec(&mut self, progression: &Progression) {
+        let (attribute_points, talent_points) = progression.points_earned(self.level);
+        self.attributes = Attributes::default();
+        self.talents.clear();
+        self.attribute_points = attribute_points;
+        self.talent_points = talent_points;
+        self.hp = self.hp.min(self.total_max_hp());
+    }
+
+    /// Learn every ability the current level qualifies for. Returns the
+    /// newly learned ones.
+    pub fn learn_abilities(&mut self) -> Vec<Ability> {
+        let learned: Vec<Ability> = Ability::ALL
+            .into_iter()
+            .filter(|a| a.level_required() <= self.level && !self.abilities.contains(a))
+            .collect();
+        self.abilities.extend(&learned);
+        learned
+    }
+
+    pub fn restore_stamina(&mut self, amount: i32) {
+        self.stamina = (self.stamina + amount).min(self.max_stamina);
+    }
+
+    pub fn weapon(&self) -> Option<&Item> {
+        self.equipment.main_hand.as_ref()
     }
 
     pub fn total_attack(&self) -> i32 {
-        self.attack + self.best_weapon_damage()
+        self.attack
+            + self.equipment.total_bonus().attack
+            + self.attributes.strength
+            + self.talent_bonus().attack
+    }
+
+    pub fn total_defense(&self) -> i32 {
+        self.defense
+            + self.equipment.total_bonus().defense
+            + self.attributes.vitality / 3
+            + self.talent_bonus().defense
+    }
+
+    pub fn total_max_hp(&self) -> i32 {
+        self.max_hp
+            + self.equipment.total_bonus().max_hp
+            + self.attributes.vitality * 3
+            + self.talent_bonus().max_hp
+    }
+
+    /// Move an inventory item into its slot, returning whatever was there
+    /// to the bag.
+    pub fn equip(&mut self, index: usize) -> Result<String, String> {
+        let item = self.inventory.get(index).ok_or("Nothing selected.")?;
+        let slot = item
+            .equip_slot()
+            .ok_or_else(|| format!("{} can't be equipped.", item.name))?;
+
+        let item = self.inventory.remove(index);
+        let message = format!("You equip the {} ({}).", item.name, slot.label());
+        if let Some(old) = self.equipment.slot_mut(slot).replace(item) {
+            self.inventory.push(old);
+        }
+        self.hp = self.hp.min(self.total_max_hp());
+        Ok(message)
+    }
+
+    /// Move the item in `slot` back to the bag, if there's room.
+    pub fn unequip(&mut self, slot: EquipSlot) -> Result<String, String> {
+        let item = self
+            .equipment
+            .get(slot)
+            .ok_or_else(|| format!("Nothing is equipped in your {}.", slot.label().to_lowercase()))?;
+        if !self.can_carry(item) {
+            return Err(format!("Your pack is too full to unequip the {}.", item.name));
+        }
+        let item = self.equipment.slot_mut(slot).take().expect("slot checked above");
+        let message = format!("You unequip the {}.", item.name);
+        self.inventory.push(item);
+        self.hp = self.hp.min(self.total_max_hp());
+        Ok(message)
+    }
+
+    /// Older saves hold gear that couldn't be worn yet as inert misc
+    /// stacks; give it the type, slot and bonus it has now, one per slot.
+    pub fn migrate_legacy_items(&mut self) {
+        let mut migrated = Vec::new();
+        for item in std::mem::take(&mut self.inventory) {
+            let upgrade = match item.base_name() {
+                _ if item.item_type != ItemType::Misc => None,
+                "Bone Shield" => Some((
+                    ItemType::Armor,
+                    Some(EquipSlot::OffHand),
+                    StatBonus::default(),
+                )),
+                "Guardian's Amulet" => Some((
+                    ItemType::Trinket,
+                    None,
+                    StatBonus {
+                        attack: 2,
+                        defense: 1,
+                        max_hp: 10,
+                    },
+                )),
+                _ => None,
+            };
+            let Some((item_type, slot, bonus)) = upgrade else {
+                migrated.push(item);
+                continue;
+            };
+            let gear = Item {
+                item_type,
+                slot,
+                bonus,
+                quantity: 1,
+                ..item
+            };
+            for _ in 0..item.quantity {
+                migrated.push(gear.clone());
+            }
+        }
+        self.inventory = migrated;
+    }
+
+    /// Older saves kept weapons loose in the bag; arm the best one.
+    pub fn equip_best_weapon_if_unarmed(&mut self) {
+        if self.equipment.main_hand.is_some() {
+            return;
+        }
+        if let Some(index) = self
+            .inventory
+            .iter()
+            .enumerate()
+            .filter(|(_, i)| i.item_type == ItemType::Weapon)
+            .max_by_key(|(_, i)| i.value)
+            .map(|(index, _)| index)
+        {
+            let _ = self.equip(index);
+        }
+    }
+
+    /// Bonus added to the player's d20 to-hit roll.
+    pub fn accuracy(&self) -> i32 {
+        2 + self.level as i32 + self.attributes.agility / 3 + self.talent_bonus().accuracy
+    }
+
+    /// Added to the d20 when fleeing.
+    pub fn flee_bonus(&self) -> i32 {
+        self.attributes.agility + self.talent_bonus().flee
+    }
+
+    /// Lowest natural d20 that counts as a critical hit.
+    pub fn crit_on(&self) -> u32 {
+        (20 - self.attributes.luck / 4 - self.talent_bonus().crit_range).max(15) as u32
     }
 
-    pub fn potion_count(&self) -> usize {
+    /// Target number enemies must meet on d20 + accuracy to hit.
+    pub fn armor_class(&self) -> i32 {
+        10 + self.total_defense() / 2
+    }
+
+    pub fn potion_count(&self) -> u32 {
         self.inventory
             .iter()
             .filter(|i| i.item_type == ItemType::Potion)
-            .count()
+            .map(|i| i.quantity)
+            .sum()
+    }
+
+    // ── Corpse runs ──────────────────────────────────────────────────
+
+    /// Take off everything worn and carried, for leaving on a corpse.
+    pub fn strip_gear(&mut self) -> (Equipment, Vec<Item>) {
+        let equipment = std::mem::take(&mut self.equipment);
+        let items = std::mem::take(&mut self.inventory);
+        self.hp = self.hp.min(self.total_max_hp());
+        (equipment, items)
+    }
+
+    /// Take gear back from a corpse: worn items go back into free slots,
+    /// the rest into the bag. Returns whatever didn't fit.
+    pub fn reclaim(&mut self, equipment: Equipment, items: Vec<Item>) -> Vec<Item> {
+        let mut left = Vec::new();
+        for slot in EquipSlot::ALL {
+            let Some(item) = equipment.get(slot).cloned() else {
+                continue;
+            };
+            let worn = self.equipment.slot_mut(slot);
+            if worn.is_none() {
+                *worn = Some(item);
+            } else if let Err(item) = self.add_item(item) {
+                left.push(*item);
+            }
+        }
+        for item in items {
+            if let Err(item) = self.add_item(item) {
+                left.push(*item);
+            }
+        }
+        left
+    }
+
+    // ── Bag ──────────────────────────────────────────────────────────
+
+    pub fn bag_full(&self) -> bool {
+        self.inventory.len() >= BAG_CAPACITY
+    }
+
+    /// Whether `item` fits: either it joins an existing stack or a slot is free.
+    pub fn can_carry(&self, item: &Item) -> bool {
+        !self.bag_full() || self.inventory.iter().any(|i| i.stacks_with(item))
+    }
+
+    /// Put an item in the bag, merging it into a matching stack. Hands the
+    /// item back if the bag is full.
+    pub fn add_item(&mut self, item: Item) -> Result<(), Box<Item>> {
+        if let Some(stack) = self.inventory.iter_mut().find(|i| i.stacks_with(&item)) {
+            stack.quantity += item.quantity;
+            return Ok(());
+        }
+        if self.bag_full() {
+            return Err(Box::new(item));
+        }
+        self.inventory.push(item);
+        Ok(())
+    }
+
+    /// Take a single item off the stack at `index`.
+    pub fn remove_one(&mut self, index: usize) -> Item {
+        let stack = &mut self.inventory[index];
+        if stack.quantity > 1 {
+            stack.quantity -= 1;
+            Item {
+                quantity: 1,
+                ..stack.clone()
+            }
+        } else {
+            self.inventory.remove(index)
+        }
+    }
+
+    /// How many of the item with this base name the bag holds.
+    pub fn count_item(&self, base_name: &str) -> u32 {
+        self.inventory
+            .iter()
+            .filter(|i| i.base_name() == base_name)
+            .map(|i| i.quantity)
+            .sum()
+    }
+
+    /// Remove `quantity` of the item with this base name from the bag.
+    /// Takes nothing and returns false if the bag holds fewer.
+    pub fn remove_items(&mut self, base_name: &str, mut quantity: u32) -> bool {
+        if self.count_item(base_name) < quantity {
+            return false;
+        }
+        while quantity > 0 {
+            let Some(index) = self
+                .inventory
+                .iter()
+                .position(|i| i.base_name() == base_name)
+            else {
+                break;
+            };
+            self.remove_one(index);
+            quantity -= 1;
+        }
+        true
+    }
+
+    /// Group the bag by item type, then name.
+    pub fn sort_inventory(&mut self) {
+        self.inventory
+            .sort_by(|a, b| a.item_type.cmp(&b.item_type).then_with(|| a.name.cmp(&b.name)));
     }
 
-    /// Use the first potion in inventory. Returns heal amount or None.
-    pub fn use_potion(&mut self) -> Option<i32> {
-        if let Some(idx) = self
+    /// Use the first potion in inventory. Returns the consumed potion
+    /// (already healed for its value) or None.
+    pub fn use_potion(&mut self) -> Option<Item> {
+        let idx = self
             .inventory
             .iter()
-            .position(|i| i.item_type == ItemType::Potion)
-        {
-            let value = self.inventory[idx].value;
-            self.inventory.remove(idx);
-            self.heal(value);
-            Some(value)
-        } else {
-            None
+            .position(|i| i.item_type == ItemType::Potion)?;
+        self.use_item(idx).ok()
+    }
+
+    /// Consume a usable inventory item. Returns it (already applied).
+    pub fn use_item(&mut self, index: usize) -> Result<Item, String> {
+        let item = self.inventory.get(index).ok_or("Nothing selected.")?;
+        if item.item_type != ItemType::Potion {
+            return Err(format!("You can't use the {} right now.", item.name));
+        }
+        let item = self.remove_one(index);
+        self.heal(item.value);
+        self.stats.potions_used += 1;
+        Ok(item)
+    }
+
+    /// Drop the whole stack at `index`.
+    pub fn drop_item(&mut self, index: usize) -> Option<Item> {
+        (index < self.inventory.len()).then(|| self.inventory.remove(index))
+    }
+
+    // ── Tile depletion ───────────────────────────────────────────────
+
+    pub fn is_depleted(&self, tile_id: usize, event_index: usize) -> bool {
+        let now = chrono::Utc::now();
+        self.depleted
+            .iter()
+            .any(|d| d.tile_id == tile_id && d.event_index == event_index && d.is_active(now))
+    }
+
+    pub fn deplete(&mut self, tile_id: usize, event_index: usize, respawn: Respawn) {
+        let now = chrono::Utc::now();
+        self.depleted.retain(|d| {
+            d.is_active(now) && !(d.tile_id == tile_id && d.event_index == event_index)
+        });
+        self.depleted
+            .push(DepletedEvent::new(tile_id, event_index, respawn));
+    }
+
+    /// Resting brings back everything marked to respawn on rest.
+    pub fn respawn_on_rest(&mut self) {
+        let now = chrono::Utc::now();
+        self.depleted
+            .retain(|d| d.respawn != Respawn::OnRest && d.is_active(now));
+    }
+
+    // ── Quests and flags ─────────────────────────────────────────────
+
+    pub fn quest(&self, id: &str) -> Option<&QuestState> {
+        self.quests.iter().find(|q| q.id == id)
+    }
+
+    pub fn quest_status(&self, id: &str) -> Option<QuestStatus> {
+        self.quest(id).map(|q| q.status)
+    }
+
+    /// Returns false if the quest was already taken.
+    pub fn start_quest(&mut self, id: &str) -> bool {
+        if self.quest_status(id).is_some() {
+            return false;
         }
+        self.quests.push(QuestState {
+            id: id.to_string(),
+            status: QuestStatus::Active,
+            progress: Vec::new(),
+        });
+        true
+    }
+
+    pub fn set_flag(&mut self, flag: &str) {
+        if !self.flags.iter().any(|f| f == flag) {
+            self.flags.push(flag.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::progression::curve;
+
+    #[test]
+    fn gain_exp_applies_every_level_it_pays_for() {
+        let progression = curve(10, vec![10, 20, 30]);
+        let mut player = Player::new("Test".into(), &progression);
+        let attack = player.attack;
+
+        let gained = player.gain_exp(10 + 20 + 5, &progression);
+        assert_eq!(gained.iter().map(|l| l.level).collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(player.level, 3);
+        assert_eq!(player.exp, 5);
+        assert_eq!(player.exp_to_next, 30);
+        assert_eq!(player.attack, attack + 2 * player.growth.attack);
+        assert_eq!(player.attribute_points, 2 * progression.attribute_points);
+        assert_eq!(player.talent_points, 2 * progression.talent_points);
+        assert_eq!(player.hp, player.total_max_hp());
+    }
+
+    #[test]
+    fn gain_exp_below_the_threshold_levels_nothing() {
+        let progression = curve(10, vec![10]);
+        let mut player = Player::new("Test".into(), &progression);
+        assert!(player.gain_exp(9, &progression).is_empty());
+        assert_eq!((player.level, player.exp), (1, 9));
+    }
+
+    #[test]
+    fn gain_exp_stops_at_the_cap() {
+        let progression = curve(3, vec![10]);
+        let mut player = Player::new("Test".into(), &progression);
+        let gained = player.gain_exp(1000, &progression);
+        assert_eq!(gained.len(), 2);
+        assert_eq!(player.level, 3);
+        assert_eq!((player.exp, player.exp_to_next), (0, 0));
+        assert!(player.gain_exp(50, &progression).is_empty());
+        assert_eq!(player.exp, 0);
+    }
+
+    fn item(name: &str, item_type: ItemType) -> Item {
+        Item {
+            name: name.into(),
+            item_type,
+            ..Default::default()
+        }
+    }
+
+    fn names(items: &[Item]) -> Vec<&str> {
+        items.iter().map(|i| i.name.as_str()).collect()
+    }
+
+    #[test]
+    fn strip_gear_takes_everything_worn_and_carried() {
+        let mut player = Player::new("Test".into(), &Progression::default());
+        let worn = player.equipment.items().count();
+        let carried = player.inventory.len();
+        assert!(worn > 0 && carried > 0);
+
+        let (equipment, items) = player.strip_gear();
+        assert_eq!(equipment.items().count(), worn);
+        assert_eq!(items.len(), carried);
+        assert_eq!(player.equipment.items().count(), 0);
+        assert!(player.inventory.is_empty());
+        assert!(player.hp <= player.total_max_hp());
+    }
+
+    #[test]
+    fn reclaim_restores_what_strip_gear_took() {
+        let mut player = Player::new("Test".into(), &Progression::default());
+        let weapon = player.weapon().map(|w| w.name.clone());
+        let bag: Vec<String> = player.inventory.iter().map(|i| i.name.clone()).collect();
+
+        let (equipment, items) = player.strip_gear();
+        assert!(player.reclaim(equipment, items).is_empty());
+        assert_eq!(player.weapon().map(|w| w.name.clone()), weapon);
+        assert_eq!(names(&player.inventory), bag);
+    }
+
+    #[test]
+    fn reclaim_bags_gear_whose_slot_is_taken() {
+        let mut player = Player::new("Test".into(), &Progression::default());
+        let (equipment, items) = player.strip_gear();
+        let old = equipment.get(EquipSlot::MainHand).unwrap().name.clone();
+        player.equipment.main_hand = Some(item("Stick", ItemType::Weapon));
+
+        assert!(player.reclaim(equipment, items).is_empty());
+        assert_eq!(player.weapon().unwrap().name, "Stick");
+        assert!(player.inventory.iter().any(|i| i.name == old));
+    }
+
+    #[test]
+    fn reclaim_returns_what_will_not_fit() {
+        let mut player = Player::new("Test".into(), &Progression::default());
+        let (equipment, _) = player.strip_gear();
+        player.inventory = (0..BAG_CAPACITY)
+            .map(|i| item(&format!("Key {}", i), ItemType::Key))
+            .collect();
+        player.equipment.main_hand = Some(item("Stick", ItemType::Weapon));
+
+        let left = player.reclaim(equipment, vec![item("Rope", ItemType::Misc)]);
+        assert!(left.iter().any(|i| i.name == "Rope"));
+        assert!(left.iter().any(|i| i.item_type == ItemType::Weapon));
+        assert_eq!(player.inventory.len(), BAG_CAPACITY);
+    }
+
+    #[test]
+    fn legacy_misc_gear_becomes_wearable() {
+        let mut player = Player::new("Test".into(), &Progression::default());
+        let mut shields = item("Bone Shield", ItemType::Misc);
+        shields.value = 3;
+        shields.quantity = 2;
+        player.inventory = vec![
+            shields,
+            item("Guardian's Amulet", ItemType::Misc),
+            item("Rope", ItemType::Misc),
+        ];
+
+        player.migrate_legacy_items();
+        assert_eq!(
+            names(&player.inventory),
+            vec!["Bone Shield", "Bone Shield", "Guardian's Amulet", "Rope"]
+        );
+        assert_eq!(player.inventory[0].item_type, ItemType::Armor);
+        assert_eq!(player.inventory[0].quantity, 1);
+        assert_eq!(player.inventory[2].item_type, ItemType::Trinket);
+        assert_eq!(player.inventory[2].bonus.max_hp, 10);
+        assert_eq!(player.inventory[3].item_type, ItemType::Misc);
+
+        let defense = player.total_defense();
+        assert!(player.equip(0).is_ok());
+        assert!(player.equipment.get(EquipSlot::OffHand).is_some());
+        assert_eq!(player.total_defense(), defense + 3);
     }
 }
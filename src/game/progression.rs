@@ -0,0 +1,177 @@
+use serde::{Deserialize, Serialize};
+
+use super::class::ClassStats;
+
+/// Extra stats granted on reaching a particular level.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Milestone {
+    pub level: u32,
+    pub bonus: ClassStats,
+}
+
+/// The leveling curve, loaded from `data/progression.json` (see
+/// `persistence::load_progression`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Progression {
+    pub level_cap: u32,
+    /// EXP needed to advance from each level, starting at level 1. Levels
+    /// past the end of the table repeat the last step; an empty table
+    /// means 50 × level.
+    #[serde(default)]
+    pub exp_table: Vec<u32>,
+    /// Stats every level-up grants on top of the class's growth.
+    #[serde(default)]
+    pub growth: ClassStats,
+    #[serde(default)]
+    pub milestones: Vec<Milestone>,
+    /// Attribute points handed out per level-up.
+    #[serde(default = "default_attribute_points")]
+    pub attribute_points: u32,
+    /// Talent points handed out per level-up.
+    #[serde(default = "default_talent_points")]
+    pub talent_points: u32,
+}
+
+fn default_attribute_points() -> u32 {
+    2
+}
+
+fn default_talent_points() -> u32 {
+    1
+}
+
+/// The default curve is the one saves were made under before it became
+/// data: 50 × level per step. The cap is high enough that no existing
+/// hunter is cut off by it.
+impl Default for Progression {
+    fn default() -> Self {
+        Self {
+            level_cap: 99,
+            exp_table: Vec::new(),
+            growth: ClassStats::default(),
+            milestones: vec![
+                Milestone {
+                    level: 5,
+                    bonus: ClassStats {
+                        max_hp: 5,
+                        defense: 1,
+                        ..Default::default()
+                    },
+                },
+                Milestone {
+                    level: 10,
+                    bonus: ClassStats {
+                        max_hp: 10,
+                        attack: 2,
+                        stamina: 2,
+                        ..Default::default()
+                    },
+                },
+            ],
+            attribute_points: default_attribute_points(),
+            talent_points: default_talent_points(),
+        }
+    }
+}
+
+impl Progression {
+    /// EXP needed to leave `level`, or 0 at the cap.
+    pub fn exp_to_next(&self, level: u32) -> u32 {
+        if level >= self.level_cap {
+            return 0;
+        }
+        let index = (level.max(1) - 1) as usize;
+        self.exp_table
+            .get(index)
+            .or(self.exp_table.last())
+            .copied()
+            .unwrap_or(50 * level)
+    }
+
+    /// EXP spent on every level below `level`.
+    pub fn total_exp(&self, level: u32) -> u32 {
+        (1..level).map(|l| self.exp_to_next(l)).sum()
+    }
+
+    /// Stats granted on reaching `level`, before class growth.
+    pub fn bonus_at(&self, level: u32) -> ClassStats {
+        let mut bonus = self.growth;
+        for milestone in self.milestones.iter().filter(|m| m.level == level) {
+            bonus += milestone.bonus;
+        }
+        bonus
+    }
+
+    /// Attribute and talent points a hunter of `level` has earned in all.
+    pub fn points_earned(&self, level: u32) -> (u32, u32) {
+        let levels = level.saturating_sub(1);
+        (levels * self.attribute_points, levels * self.talent_points)
+    }
+}
+
+/// One level gained, for the log and the level-up panel.
+#[derive(Debug, Clone)]
+pub struct LevelUp {
+    pub level: u32,
+    pub gains: ClassStats,
+    pub attribute_points: u32,
+    pub talent_points: u32,
+}
+
+/// Test fixture: the default curve with its cap and table replaced.
+#[cfg(test)]
+pub(crate) fn curve(level_cap: u32, exp_table: Vec<u32>) -> Progression {
+    Progression {
+        level_cap,
+        exp_table,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_curve_matches_the_original_fifty_per_level() {
+        let progression = Progression::default();
+        for level in 1..30 {
+            assert_eq!(progression.exp_to_next(level), 50 * level);
+        }
+        assert_eq!(progression.exp_to_next(progression.level_cap), 0);
+    }
+
+    #[test]
+    fn exp_to_next_reads_the_table_then_repeats_its_last_step() {
+        let progression = curve(6, vec![10, 20, 40]);
+        assert_eq!(progression.exp_to_next(0), 10);
+        assert_eq!(progression.exp_to_next(1), 10);
+        assert_eq!(progression.exp_to_next(3), 40);
+        assert_eq!(progression.exp_to_next(5), 40);
+        assert_eq!(progression.exp_to_next(6), 0);
+        assert_eq!(progression.exp_to_next(7), 0);
+    }
+
+    #[test]
+    fn total_exp_sums_every_step_below() {
+        let progression = curve(6, vec![10, 20, 40]);
+        assert_eq!(progression.total_exp(1), 0);
+        assert_eq!(progression.total_exp(4), 70);
+        assert_eq!(progression.total_exp(6), 150);
+    }
+
+    #[test]
+    fn bonus_at_adds_milestones_to_growth() {
+        let progression = Progression::default();
+        assert_eq!(progression.bonus_at(4).max_hp, 0);
+        assert_eq!(progression.bonus_at(5).max_hp, 5);
+        assert_eq!(progression.bonus_at(10).attack, 2);
+    }
+
+    #[test]
+    fn points_earned_skips_level_one() {
+        let progression = Progression::default();
+        assert_eq!(progression.points_earned(1), (0, 0));
+        assert_eq!(progression.points_earned(4), (6, 3));
+    }
+}
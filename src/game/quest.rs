@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+
+use super::player::{Item, Player};
+
+// ── Definitions (world pack) ─────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Objective {
+    /// Defeat this many of the named enemy.
+    Kill { enemy: String, count: u32 },
+    /// Hold this many of the item (by base name); handed over on turn-in.
+    Collect { item: String, count: u32 },
+    /// Set foot on the tile with this id.
+    Reach { tile: usize, name: String },
+}
+
+impl Objective {
+    /// Progress needed to satisfy the objective.
+    fn target(&self) -> u32 {
+        match self {
+            Objective::Kill { count, .. } | Objective::Collect { count, .. } => *count,
+            Objective::Reach { .. } => 1,
+        }
+    }
+
+    /// Current progress, capped at the target. Collection is counted live
+    /// from the bag; the rest is tracked in `QuestState`.
+    pub fn progress(&self, tracked: u32, player: &Player) -> u32 {
+        let progress = match self {
+            Objective::Collect { item, .. } => player.count_item(item),
+            _ => tracked,
+        };
+        progress.min(self.target())
+    }
+
+    pub fn is_done(&self, tracked: u32, player: &Player) -> bool {
+        self.progress(tracked, player) >= self.target()
+    }
+
+    /// Line for the quest log, e.g. "Slay Wild Wolf (2/3)".
+    pub fn describe(&self, tracked: u32, player: &Player) -> String {
+        let progress = self.progress(tracked, player);
+        match self {
+            Objective::Kill { enemy, count } => format!("Slay {} ({}/{})", enemy, progress, count),
+            Objective::Collect { item, count } => {
+                format!("Gather {} ({}/{})", item, progress, count)
+            }
+            Objective::Reach { name, .. } => format!("Reach the {} ({}/1)", name, progress),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuestReward {
+    pub exp: u32,
+    pub gold: u32,
+    pub items: Vec<Item>,
+}
+
+impl QuestReward {
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if self.exp > 0 {
+            parts.push(format!("{} EXP", self.exp));
+        }
+        if self.gold > 0 {
+            parts.push(format!("{} gold", self.gold));
+        }
+        parts.extend(self.items.iter().map(|i| i.display_name()));
+        parts.join(", ")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Quest {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub objectives: Vec<Objective>,
+    #[serde(default)]
+    pub reward: QuestReward,
+    /// NPC the quest is handed in to. Quests without one complete as soon
+    /// as every objective is met.
+    #[serde(default)]
+    pub turn_in: Option<String>,
+}
+
+impl Quest {
+    pub fn is_ready(&self, state: &QuestState, player: &Player) -> bool {
+        self.objectives
+            .iter()
+            .enumerate()
+            .all(|(i, objective)| objective.is_done(state.tracked(i), player))
+    }
+}
+
+/// Something that happened in the world that may advance an objective.
+#[derive(Debug, Clone)]
+pub enum QuestEvent {
+    Kill(String),
+    Reach(usize),
+}
+
+// ── Per-hunter state (player save) ───────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum QuestStatus {
+    Active,
+    Completed,
+}
+
+/// A quest the hunter has picked up, by id.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestState {
+    pub id: String,
+    pub status: QuestStatus,
+    /// Tracked progress per objective, in the quest's objective order.
+    #[serde(default)]
+    pub progress: Vec<u32>,
+}
+
+impl QuestState {
+    pub fn tracked(&self, objective: usize) -> u32 {
+        self.progress.get(objective).copied().unwrap_or(0)
+    }
+
+    /// Apply an event to the matching objectives. Returns the indices of
+    /// the objectives that advanced.
+    pub fn record(&mut self, quest: &Quest, event: &QuestEvent) -> Vec<usize> {
+        self.progress.resize(quest.objectives.len(), 0);
+        let mut advanced = Vec::new();
+        for (i, objective) in quest.objectives.iter().enumerate() {
+            let hit = match (objective, event) {
+                (Objective::Kill { enemy, count }, QuestEvent::Kill(name)) => {
+                    enemy == name && self.progress[i] < *count
+                }
+                (Objective::Reach { tile, .. }, QuestEvent::Reach(id)) => {
+                    tile == id && self.progress[i] == 0
+                }
+                _ => false,
+            };
+            if hit {
+                self.progress[i] += 1;
+                advanced.push(i);
+            }
+        }
+        advanced
+    }
+}
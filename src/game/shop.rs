@@ -0,0 +1,66 @@
+use super::player::{Item, ItemType, Player};
+use super::world::Merchant;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShopTab {
+    Buy,
+    Sell,
+}
+
+/// An open trade with a merchant.
+#[derive(Debug, Clone)]
+pub struct ShopState {
+    pub merchant: Merchant,
+    pub tab: ShopTab,
+}
+
+impl ShopState {
+    pub fn new(merchant: Merchant) -> Self {
+        Self {
+            merchant,
+            tab: ShopTab::Buy,
+        }
+    }
+
+    /// What the merchant pays for one of `item`, or None if they won't take it.
+    pub fn sell_price(&self, item: &Item) -> Option<u32> {
+        if item.item_type == ItemType::Key || item.price == 0 {
+            return None;
+        }
+        Some(((item.price as f64 * self.merchant.buy_rate) as u32).max(1))
+    }
+
+    pub fn buy(&self, player: &mut Player, index: usize) -> Result<String, String> {
+        let item = self.merchant.stock.get(index).ok_or("Nothing selected.")?;
+        if player.gold < item.price {
+            return Err(format!(
+                "The {} costs {} gold; you have {}.",
+                item.name, item.price, player.gold
+            ));
+        }
+        if !player.can_carry(item) {
+            return Err("Your pack is full.".into());
+        }
+        player.gold -= item.price;
+        let message = format!("You buy a {} for {} gold.", item.name, item.price);
+        let _ = player.add_item(Item {
+            quantity: 1,
+            ..item.clone()
+        });
+        Ok(message)
+    }
+
+    /// Sell one of the bag item at `index`.
+    pub fn sell(&self, player: &mut Player, index: usize) -> Result<String, String> {
+        let item = player.inventory.get(index).ok_or("Nothing selected.")?;
+        let Some(price) = self.sell_price(item) else {
+            return Err(format!(
+                "{} has no use for the {}.",
+                self.merchant.name, item.name
+            ));
+        };
+        let item = player.remove_one(index);
+        player.gold += price;
+        Ok(format!("You sell the {} for {} gold.", item.name, price))
+    }
+}
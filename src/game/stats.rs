@@ -0,0 +1,109 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A hunter's lifetime record, kept on the save.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Stats {
+    /// When the hunter was created. Saves from before stats start counting
+    /// from when they were first loaded.
+    pub started: DateTime<Utc>,
+    /// Enemies defeated, by name.
+    pub kills: BTreeMap<String, u32>,
+    pub boss_kills: u32,
+    /// Tiles travelled plus actions taken in combat.
+    pub turns: u32,
+    /// When the current life began: creation, or the last Normal restart.
+    /// Victories are timed from here.
+    pub run_started: DateTime<Utc>,
+    /// `turns` taken in the current life.
+    pub run_turns: u32,
+    /// Damage landed on enemies and their minions, status ticks included.
+    pub damage_dealt: u32,
+    /// Damage taken after defense and difficulty.
+    pub damage_taken: u32,
+    /// The hardest single strike the hunter has landed.
+    pub biggest_hit: u32,
+    pub potions_used: u32,
+    /// Ids of every tile the hunter has stood on.
+    pub visited: BTreeSet<usize>,
+    pub fights_fled: u32,
+    pub deaths: u32,
+    /// Seconds spent in the world, flushed on every save.
+    pub play_seconds: u64,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self {
+            started: Utc::now(),
+            kills: BTreeMap::new(),
+            boss_kills: 0,
+            turns: 0,
+            run_started: Utc::now(),
+            run_turns: 0,
+            damage_dealt: 0,
+            damage_taken: 0,
+            biggest_hit: 0,
+            potions_used: 0,
+            visited: BTreeSet::new(),
+            fights_fled: 0,
+            deaths: 0,
+            play_seconds: 0,
+        }
+    }
+}
+
+impl Stats {
+    pub fn total_kills(&self) -> u32 {
+        self.kills.values().sum()
+    }
+
+    pub fn record_kill(&mut self, enemy: &str, is_boss: bool) {
+        *self.kills.entry(enemy.to_string()).or_default() += 1;
+        if is_boss {
+            self.boss_kills += 1;
+        }
+    }
+
+    pub fn take_turn(&mut self) {
+        self.turns += 1;
+        self.run_turns += 1;
+    }
+
+    /// Start timing a fresh life; lifetime totals carry on.
+    pub fn new_run(&mut self) {
+        self.run_started = Utc::now();
+        self.run_turns = 0;
+    }
+
+    /// A strike that landed for `damage`.
+    pub fn record_hit(&mut self, damage: i32) {
+        let damage = damage.max(0) as u32;
+        self.damage_dealt += damage;
+        self.biggest_hit = self.biggest_hit.max(damage);
+    }
+
+    /// Damage from status effects: counts as dealt but is never a "hit".
+    pub fn record_tick(&mut self, damage: i32) {
+        self.damage_dealt += damage.max(0) as u32;
+    }
+
+    pub fn record_damage_taken(&mut self, damage: i32) {
+        self.damage_taken += damage.max(0) as u32;
+    }
+
+    pub fn visit(&mut self, tile_id: usize) {
+        self.visited.insert(tile_id);
+    }
+
+    /// The enemy this hunter has killed most, with the count.
+    pub fn favourite_prey(&self) -> Option<(&str, u32)> {
+        self.kills
+            .iter()
+            .max_by_key(|&(_, count)| *count)
+            .map(|(name, count)| (name.as_str(), *count))
+    }
+}
@@ -0,0 +1,268 @@
+use std::fmt;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+// ── Effect kinds ─────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StatusKind {
+    Poison,
+    Bleed,
+    Stun,
+    Burn,
+    Regeneration,
+    Weakened,
+}
+
+/// How a fresh application combines with an effect of the same kind.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stacking {
+    /// Potency adds up, duration takes the longer of the two.
+    Intensify,
+    /// Strongest potency wins, duration resets.
+    Refresh,
+}
+
+impl StatusKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            StatusKind::Poison => "Poison",
+            StatusKind::Bleed => "Bleed",
+            StatusKind::Stun => "Stun",
+            StatusKind::Burn => "Burn",
+            StatusKind::Regeneration => "Regeneration",
+            StatusKind::Weakened => "Weakened",
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            StatusKind::Poison => "☣",
+            StatusKind::Bleed => "♦",
+            StatusKind::Stun => "✦",
+            StatusKind::Burn => "✹",
+            StatusKind::Regeneration => "✚",
+            StatusKind::Weakened => "↓",
+        }
+    }
+
+    pub fn stacking(&self) -> Stacking {
+        match self {
+            StatusKind::Poison | StatusKind::Bleed => Stacking::Intensify,
+            StatusKind::Stun
+            | StatusKind::Burn
+            | StatusKind::Regeneration
+            | StatusKind::Weakened => Stacking::Refresh,
+        }
+    }
+
+    pub fn is_harmful(&self) -> bool {
+        !matches!(self, StatusKind::Regeneration)
+    }
+}
+
+// ── Effects ──────────────────────────────────────────────────────────
+
+/// A single effect with its remaining duration.
+///
+/// `potency` is damage per turn for Poison/Bleed/Burn, healing per turn
+/// for Regeneration, and percent damage reduction for Weakened.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    pub turns: u32,
+    pub potency: i32,
+}
+
+impl StatusEffect {
+    pub fn new(kind: StatusKind, turns: u32, potency: i32) -> Self {
+        Self { kind, turns, potency }
+    }
+}
+
+impl fmt::Display for StatusEffect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.kind.icon(), self.kind.label())?;
+        if self.kind != StatusKind::Stun {
+            write!(f, " {}", self.potency)?;
+        }
+        write!(f, " ×{}", self.turns)
+    }
+}
+
+/// An effect that lands with some probability — enemy attacks, weapon
+/// hits and potions all carry one of these.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Affliction {
+    pub effect: StatusEffect,
+    /// Chance (0.0–1.0) that the effect is applied.
+    pub chance: f64,
+}
+
+impl Affliction {
+    pub fn new(kind: StatusKind, turns: u32, potency: i32, chance: f64) -> Self {
+        Self {
+            effect: StatusEffect::new(kind, turns, potency),
+            chance,
+        }
+    }
+
+    /// Roll the chance; returns the effect if it lands.
+    pub fn roll(&self) -> Option<StatusEffect> {
+        let roll: f64 = rand::thread_rng().r#gen();
+        (roll < self.chance).then(|| self.effect.clone())
+    }
+}
+
+// ── Effect container ─────────────────────────────────────────────────
+
+/// What happened when a combatant's effects ticked at the start of its turn.
+#[derive(Debug, Default)]
+pub struct TickReport {
+    pub damage: Vec<(StatusKind, i32)>,
+    pub healing: i32,
+    pub stunned: bool,
+    pub expired: Vec<StatusKind>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StatusEffects {
+    pub active: Vec<StatusEffect>,
+}
+
+impl StatusEffects {
+    /// Add an effect, merging with an existing one per its stacking rule.
+    pub fn apply(&mut self, effect: StatusEffect) {
+        if let Some(existing) = self.active.iter_mut().find(|e| e.kind == effect.kind) {
+            match effect.kind.stacking() {
+                Stacking::Intensify => {
+                    existing.potency += effect.potency;
+                    existing.turns = existing.turns.max(effect.turns);
+                }
+                Stacking::Refresh => {
+                    existing.potency = existing.potency.max(effect.potency);
+                    existing.turns = effect.turns;
+                }
+            }
+        } else {
+            self.active.push(effect);
+        }
+    }
+
+    pub fn potency(&self, kind: StatusKind) -> i32 {
+        self.active
+            .iter()
+            .find(|e| e.kind == kind)
+            .map(|e| e.potency)
+            .unwrap_or(0)
+    }
+
+    /// Scale outgoing damage down if the combatant is weakened.
+    pub fn modify_damage(&self, damage: i32) -> i32 {
+        let reduction = self.potency(StatusKind::Weakened).clamp(0, 100);
+        (damage * (100 - reduction) / 100).max(1)
+    }
+
+    /// Resolve one turn of effects: damage/heal over time, stun check,
+    /// then count every effect down and drop the expired ones.
+    pub fn tick(&mut self) -> TickReport {
+        let mut report = TickReport::default();
+
+        for effect in &mut self.active {
+            match effect.kind {
+                StatusKind::Poison | StatusKind::Bleed | StatusKind::Burn => {
+                    report.damage.push((effect.kind, effect.potency));
+                }
+                StatusKind::Regeneration => report.healing += effect.potency,
+                StatusKind::Stun => report.stunned = true,
+                StatusKind::Weakened => {}
+            }
+            effect.turns = effect.turns.saturating_sub(1);
+        }
+
+        self.active.retain(|e| {
+            if e.turns == 0 {
+                report.expired.push(e.kind);
+                false
+            } else {
+                true
+            }
+        });
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn effects(list: &[StatusEffect]) -> StatusEffects {
+        let mut effects = StatusEffects::default();
+        for effect in list {
+            effects.apply(effect.clone());
+        }
+        effects
+    }
+
+    #[test]
+    fn intensifying_effects_add_potency_and_keep_the_longer_duration() {
+        let effects = effects(&[
+            StatusEffect::new(StatusKind::Poison, 3, 2),
+            StatusEffect::new(StatusKind::Poison, 1, 1),
+        ]);
+        assert_eq!(effects.active.len(), 1);
+        assert_eq!(effects.potency(StatusKind::Poison), 3);
+        assert_eq!(effects.active[0].turns, 3);
+    }
+
+    #[test]
+    fn refreshing_effects_keep_the_strongest_and_reset_duration() {
+        let effects = effects(&[
+            StatusEffect::new(StatusKind::Burn, 4, 3),
+            StatusEffect::new(StatusKind::Burn, 2, 1),
+        ]);
+        assert_eq!(effects.active.len(), 1);
+        assert_eq!(effects.potency(StatusKind::Burn), 3);
+        assert_eq!(effects.active[0].turns, 2);
+    }
+
+    #[test]
+    fn different_kinds_stack_side_by_side() {
+        let effects = effects(&[
+            StatusEffect::new(StatusKind::Bleed, 2, 1),
+            StatusEffect::new(StatusKind::Stun, 1, 1),
+        ]);
+        assert_eq!(effects.active.len(), 2);
+        assert_eq!(effects.potency(StatusKind::Regeneration), 0);
+    }
+
+    #[test]
+    fn tick_reports_then_expires() {
+        let mut effects = effects(&[
+            StatusEffect::new(StatusKind::Poison, 2, 2),
+            StatusEffect::new(StatusKind::Regeneration, 1, 3),
+            StatusEffect::new(StatusKind::Stun, 1, 1),
+        ]);
+
+        let first = effects.tick();
+        assert_eq!(first.damage, vec![(StatusKind::Poison, 2)]);
+        assert_eq!(first.healing, 3);
+        assert!(first.stunned);
+        assert_eq!(first.expired, vec![StatusKind::Regeneration, StatusKind::Stun]);
+
+        let second = effects.tick();
+        assert!(!second.stunned);
+        assert_eq!(second.expired, vec![StatusKind::Poison]);
+        assert!(effects.active.is_empty());
+    }
+
+    #[test]
+    fn weakened_scales_damage_but_never_below_one() {
+        let effects = effects(&[StatusEffect::new(StatusKind::Weakened, 2, 30)]);
+        assert_eq!(effects.modify_damage(10), 7);
+        assert_eq!(effects.modify_damage(1), 1);
+        assert_eq!(StatusEffects::default().modify_damage(10), 10);
+    }
+}
@@ -0,0 +1,220 @@
+use serde::{Deserialize, Serialize};
+
+// ── Attributes ───────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Attribute {
+    Strength,
+    Vitality,
+    Agility,
+    Luck,
+}
+
+impl Attribute {
+    pub const ALL: [Attribute; 4] = [
+        Attribute::Strength,
+        Attribute::Vitality,
+        Attribute::Agility,
+        Attribute::Luck,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Attribute::Strength => "Strength",
+            Attribute::Vitality => "Vitality",
+            Attribute::Agility => "Agility",
+            Attribute::Luck => "Luck",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Attribute::Strength => "+1 attack per point.",
+            Attribute::Vitality => "+3 max HP per point, +1 defense every 3 points.",
+            Attribute::Agility => "+1 to flee rolls per point, +1 accuracy every 3 points.",
+            Attribute::Luck => "Critical hits on one lower natural roll every 4 points.",
+        }
+    }
+}
+
+/// Points spent into each attribute.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Attributes {
+    pub strength: i32,
+    pub vitality: i32,
+    pub agility: i32,
+    pub luck: i32,
+}
+
+impl Attributes {
+    pub fn get(&self, attribute: Attribute) -> i32 {
+        match attribute {
+            Attribute::Strength => self.strength,
+            Attribute::Vitality => self.vitality,
+            Attribute::Agility => self.agility,
+            Attribute::Luck => self.luck,
+        }
+    }
+
+    pub fn raise(&mut self, attribute: Attribute) {
+        match attribute {
+            Attribute::Strength => self.strength += 1,
+            Attribute::Vitality => self.vitality += 1,
+            Attribute::Agility => self.agility += 1,
+            Attribute::Luck => self.luck += 1,
+        }
+    }
+
+    pub fn total(&self) -> i32 {
+        self.strength + self.vitality + self.agility + self.luck
+    }
+}
+
+// ── Talents ──────────────────────────────────────────────────────────
+
+/// Combat bonuses from talents, summed into the player's totals.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TalentBonus {
+    pub attack: i32,
+    pub defense: i32,
+    pub max_hp: i32,
+    pub accuracy: i32,
+    pub flee: i32,
+    /// How many natural rolls below 20 also crit.
+    pub crit_range: i32,
+}
+
+impl std::ops::AddAssign for TalentBonus {
+    fn add_assign(&mut self, other: Self) {
+        self.attack += other.attack;
+        self.defense += other.defense;
+        self.max_hp += other.max_hp;
+        self.accuracy += other.accuracy;
+        self.flee += other.flee;
+        self.crit_range += other.crit_range;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Branch {
+    Might,
+    Guard,
+    Cunning,
+}
+
+impl Branch {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Branch::Might => "Might",
+            Branch::Guard => "Guard",
+            Branch::Cunning => "Cunning",
+        }
+    }
+}
+
+/// One node of the talent tree. Each branch is taken in order: a talent
+/// needs the one above it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Talent {
+    Brawler,
+    KeenEdge,
+    Executioner,
+    ThickSkin,
+    Stalwart,
+    Bulwark,
+    LightFeet,
+    SteadyAim,
+    Opportunist,
+}
+
+impl Talent {
+    /// In tree order: each branch top to bottom.
+    pub const ALL: [Talent; 9] = [
+        Talent::Brawler,
+        Talent::KeenEdge,
+        Talent::Executioner,
+        Talent::ThickSkin,
+        Talent::Stalwart,
+        Talent::Bulwark,
+        Talent::LightFeet,
+        Talent::SteadyAim,
+        Talent::Opportunist,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Talent::Brawler => "Brawler",
+            Talent::KeenEdge => "Keen Edge",
+            Talent::Executioner => "Executioner",
+            Talent::ThickSkin => "Thick Skin",
+            Talent::Stalwart => "Stalwart",
+            Talent::Bulwark => "Bulwark",
+            Talent::LightFeet => "Light Feet",
+            Talent::SteadyAim => "Steady Aim",
+            Talent::Opportunist => "Opportunist",
+        }
+    }
+
+    pub fn branch(&self) -> Branch {
+        match self {
+            Talent::Brawler | Talent::KeenEdge | Talent::Executioner => Branch::Might,
+            Talent::ThickSkin | Talent::Stalwart | Talent::Bulwark => Branch::Guard,
+            Talent::LightFeet | Talent::SteadyAim | Talent::Opportunist => Branch::Cunning,
+        }
+    }
+
+    /// The talent that must be learned first, if any.
+    pub fn requires(&self) -> Option<Talent> {
+        match self {
+            Talent::KeenEdge => Some(Talent::Brawler),
+            Talent::Executioner => Some(Talent::KeenEdge),
+            Talent::Stalwart => Some(Talent::ThickSkin),
+            Talent::Bulwark => Some(Talent::Stalwart),
+            Talent::SteadyAim => Some(Talent::LightFeet),
+            Talent::Opportunist => Some(Talent::SteadyAim),
+            _ => None,
+        }
+    }
+
+    pub fn bonus(&self) -> TalentBonus {
+        let none = TalentBonus::default();
+        match self {
+            Talent::Brawler => TalentBonus { attack: 2, ..none },
+            Talent::KeenEdge => TalentBonus { crit_range: 1, ..none },
+            Talent::Executioner => TalentBonus {
+                attack: 2,
+                accuracy: 2,
+                ..none
+            },
+            Talent::ThickSkin => TalentBonus { defense: 1, ..none },
+            Talent::Stalwart => TalentBonus { max_hp: 10, ..none },
+            Talent::Bulwark => TalentBonus {
+                defense: 2,
+                max_hp: 5,
+                ..none
+            },
+            Talent::LightFeet => TalentBonus { flee: 3, ..none },
+            Talent::SteadyAim => TalentBonus { accuracy: 2, ..none },
+            Talent::Opportunist => TalentBonus {
+                crit_range: 1,
+                attack: 1,
+                ..none
+            },
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Talent::Brawler => "+2 attack.",
+            Talent::KeenEdge => "Critical hits on a natural 19 as well.",
+            Talent::Executioner => "+2 attack and +2 accuracy.",
+            Talent::ThickSkin => "+1 defense.",
+            Talent::Stalwart => "+10 max HP.",
+            Talent::Bulwark => "+2 defense and +5 max HP.",
+            Talent::LightFeet => "+3 to flee rolls.",
+            Talent::SteadyAim => "+2 accuracy.",
+            Talent::Opportunist => "+1 attack, and critical hits one roll sooner.",
+        }
+    }
+}
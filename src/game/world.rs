@@ -1,7 +1,16 @@
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 
+use super::behavior::Behavior;
+use super::class::{self, CharacterClass};
+use super::crafting::{Ingredient, Recipe};
+use super::dialogue::Dialogue;
+use super::difficulty::Difficulty;
 use super::dice::Dice;
-use super::player::Item;
+use super::loot::{LootEntry, LootTable, Rarity};
+use super::player::{Equipment, Item};
+use super::quest::Quest;
+use super::status::{Affliction, StatusEffect};
 
 // ── Tile events ──────────────────────────────────────────────────────
 
@@ -11,22 +20,159 @@ pub struct EnemyTemplate {
     pub hp: i32,
     pub damage_dice: Dice,
     pub exp_reward: u32,
-    pub loot: Option<Item>,
+    /// Each table is rolled separately on defeat.
+    #[serde(default)]
+    pub loot: Vec<LootTable>,
     pub is_boss: bool,
+    /// Armor class: the player's d20 + accuracy must meet this to hit.
+    #[serde(default = "default_armor")]
+    pub armor: i32,
+    /// Bonus added to the enemy's d20 to-hit roll.
+    #[serde(default)]
+    pub accuracy: i32,
+    /// Status effect the enemy's attacks may inflict on the player.
+    #[serde(default)]
+    pub inflicts: Option<Affliction>,
+    #[serde(default)]
+    pub behavior: Behavior,
+    /// Boss fight stages, entered in order as their triggers are met.
+    #[serde(default)]
+    pub phases: Vec<BossPhase>,
+    /// When the enemy turns up again after being slain. Ignored for
+    /// bosses, which stay dead for every hunter.
+    #[serde(default = "default_enemy_respawn")]
+    pub respawn: Respawn,
+    /// Gold dropped on defeat, e.g. "2d6+5".
+    #[serde(default)]
+    pub gold: Option<Dice>,
+}
+
+fn default_armor() -> i32 {
+    10
+}
+
+fn default_enemy_respawn() -> Respawn {
+    Respawn::AfterMinutes(5)
+}
+
+/// When a boss phase kicks in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PhaseTrigger {
+    /// Boss HP falls to or below this fraction (0.0–1.0) of its max.
+    HpBelow(f64),
+    /// This many rounds have passed.
+    Turn(u32),
+}
+
+/// A stage of a boss fight. Every change is optional; unset fields keep
+/// whatever the previous phase used.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BossPhase {
+    pub name: String,
+    pub trigger: PhaseTrigger,
+    /// Logged to the combat log when the phase begins.
+    pub narration: String,
+    #[serde(default)]
+    pub damage_dice: Option<Dice>,
+    #[serde(default)]
+    pub behavior: Option<Behavior>,
+    /// Extra foes summoned when the phase begins.
+    #[serde(default)]
+    pub minions: Vec<EnemyTemplate>,
+    /// Lingering hazard kept on the player for the rest of the fight.
+    #[serde(default)]
+    pub arena_effect: Option<StatusEffect>,
+}
+
+/// A trader with an endless stock. Sells at each item's `price` and buys
+/// anything priced at a fraction of it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Merchant {
+    pub name: String,
+    pub greeting: String,
+    pub stock: Vec<Item>,
+    /// Fraction (0.0–1.0) of an item's price paid when buying from the player.
+    #[serde(default = "default_buy_rate")]
+    pub buy_rate: f64,
+}
+
+fn default_buy_rate() -> f64 {
+    0.5
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TileEvent {
     Enemy(EnemyTemplate),
-    ItemPickup(Item),
+    ItemPickup {
+        item: Item,
+        #[serde(default)]
+        respawn: Respawn,
+    },
+    /// `dialogue` is a one-liner; NPCs with a `tree` open the dialogue
+    /// screen instead.
     Npc {
         name: String,
         dialogue: String,
+        #[serde(default)]
+        tree: Option<Dialogue>,
+    },
+    Merchant(Merchant),
+    /// Opens the crafting screen.
+    Workbench,
+    /// Opens the talent screen with the option to respec for
+    /// `respec_cost` gold per level.
+    Trainer {
+        name: String,
+        greeting: String,
+        respec_cost: u32,
     },
     Rest,
     Nothing,
 }
 
+// ── Depletion ────────────────────────────────────────────────────────
+
+/// When a used-up tile event becomes available again.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum Respawn {
+    /// Gone for good.
+    #[default]
+    Never,
+    /// Back once this many real-time minutes have passed.
+    AfterMinutes(u32),
+    /// Back after the hunter next rests.
+    OnRest,
+}
+
+/// A tile event a hunter has used up: a looted pickup or a slain enemy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DepletedEvent {
+    pub tile_id: usize,
+    /// Index into the tile's `events`.
+    pub event_index: usize,
+    pub respawn: Respawn,
+    pub at: DateTime<Utc>,
+}
+
+impl DepletedEvent {
+    pub fn new(tile_id: usize, event_index: usize, respawn: Respawn) -> Self {
+        Self {
+            tile_id,
+            event_index,
+            respawn,
+            at: Utc::now(),
+        }
+    }
+
+    /// Whether the event is still unavailable at `now`.
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        match self.respawn {
+            Respawn::Never | Respawn::OnRest => true,
+            Respawn::AfterMinutes(minutes) => now < self.at + Duration::minutes(minutes as i64),
+        }
+    }
+}
+
 // ── Tile ─────────────────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,6 +194,75 @@ pub struct WorldState {
     pub discovered_secrets: Vec<String>,
     pub global_kill_count: u32,
     pub messages: Vec<PlayerMessage>,
+    #[serde(default)]
+    pub corpses: Vec<Corpse>,
+    /// Every hunter death, oldest first.
+    #[serde(default)]
+    pub deaths: Vec<DeathRecord>,
+    /// Every hunter who saw the last ancient evil fall, oldest first.
+    #[serde(default)]
+    pub victories: Vec<VictoryRecord>,
+    /// Memorial lines ("Name, level N Class") written before deaths were
+    /// recorded in full. Kept so those names stay on the stone.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub memorial: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeathRecord {
+    pub name: String,
+    pub level: u32,
+    pub class: String,
+    pub difficulty: Difficulty,
+    pub killer: String,
+    /// Name of the tile they fell on.
+    pub tile: String,
+    pub at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VictoryRecord {
+    pub name: String,
+    pub level: u32,
+    pub class: String,
+    pub difficulty: Difficulty,
+    pub at: DateTime<Utc>,
+    /// Wall-clock seconds from the start of the winning life.
+    #[serde(default)]
+    pub seconds: Option<i64>,
+    /// Turns the winning life took, per `Stats::run_turns`.
+    #[serde(default)]
+    pub turns: Option<u32>,
+}
+
+/// Gear a hunter dropped where they died, waiting for them to walk back.
+/// Every hunter passing through sees it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Corpse {
+    pub owner: String,
+    pub tile_id: usize,
+    #[serde(default)]
+    pub equipment: Equipment,
+    #[serde(default)]
+    pub items: Vec<Item>,
+    pub at: DateTime<Utc>,
+}
+
+impl WorldState {
+    /// Names of hunters whose deaths were final, newest first, ending with
+    /// the legacy `memorial` lines.
+    pub fn memorial(&self) -> impl Iterator<Item = String> + '_ {
+        self.deaths
+            .iter()
+            .rev()
+            .filter(|d| d.difficulty == Difficulty::Hardcore)
+            .map(|d| format!("{}, level {} {}", d.name, d.level, d.class))
+            .chain(self.memorial.iter().rev().cloned())
+    }
+
+    pub fn corpse_of(&self, owner: &str) -> Option<&Corpse> {
+        self.corpses.iter().find(|c| c.owner == owner)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -57,10 +272,96 @@ pub struct PlayerMessage {
     pub tile_id: usize,
 }
 
+// ── World pack ───────────────────────────────────────────────────────
+
+/// Everything that defines a world's content. The built-in world comes
+/// from `build_world`; a custom pack can be dropped in as JSON (see
+/// `persistence::load_world_pack`), with dice written as strings like
+/// "2d6+3" or "1d20adv".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorldPack {
+    pub tiles: Vec<Tile>,
+    #[serde(default)]
+    pub recipes: Vec<Recipe>,
+    #[serde(default)]
+    pub quests: Vec<Quest>,
+    /// Classes offered at creation; packs without any get the built-in ones.
+    #[serde(default = "class::build_classes")]
+    pub classes: Vec<CharacterClass>,
+}
+
+impl WorldPack {
+    pub fn builtin() -> Self {
+        Self {
+            tiles: build_world(),
+            recipes: build_recipes(),
+            quests: build_quests(),
+            classes: class::build_classes(),
+        }
+    }
+}
+
 // ── Hardcoded world builder ──────────────────────────────────────────
 
 pub fn build_world() -> Vec<Tile> {
-    use super::player::ItemType;
+    use super::player::{EquipSlot, ItemType, StatBonus};
+    use super::status::StatusKind;
+
+    let small_potion = Item {
+        name: "Small Potion".into(),
+        description: "Restores 10 HP.".into(),
+        item_type: ItemType::Potion,
+        value: 10,
+        price: 10,
+        ..Default::default()
+    };
+    let health_potion = Item {
+        name: "Health Potion".into(),
+        description: "A red vial. Restores 15 HP.".into(),
+        item_type: ItemType::Potion,
+        value: 15,
+        price: 25,
+        ..Default::default()
+    };
+    // Shared by any table that can drop a potion.
+    let potions = LootTable::new(
+        1.0,
+        vec![
+            LootEntry::item(small_potion.clone(), 6),
+            LootEntry::item(health_potion.clone(), 3),
+        ],
+    );
+
+    let cave_bat = EnemyTemplate {
+        name: "Cave Bat".into(),
+        hp: 6,
+        damage_dice: Dice::new(1, 4, 0), // 1d4
+        exp_reward: 0,
+        loot: vec![],
+        is_boss: false,
+        armor: 13,
+        accuracy: 3,
+        inflicts: None,
+        behavior: Behavior::Aggressive,
+        phases: vec![],
+        respawn: Respawn::Never,
+        gold: None,
+    };
+    let spectral_wisp = EnemyTemplate {
+        name: "Spectral Wisp".into(),
+        hp: 8,
+        damage_dice: Dice::new(1, 4, 1), // 1d4+1
+        exp_reward: 0,
+        loot: vec![],
+        is_boss: false,
+        armor: 12,
+        accuracy: 4,
+        inflicts: None,
+        behavior: Behavior::Aggressive,
+        phases: vec![],
+        respawn: Respawn::Never,
+        gold: None,
+    };
 
     vec![
         // 0 — Village Square (safe haven, no combat)
@@ -80,7 +381,54 @@ pub fn build_world() -> Vec<Tile> {
                     dialogue: "Welcome, hunter. Dark creatures stir in the mines \
                         to the north. Be careful out there... and bring potions."
                         .into(),
+                    tree: Some(elder_maren_dialogue()),
+                },
+                TileEvent::Workbench,
+                TileEvent::Trainer {
+                    name: "Drillmaster Hale".into(),
+                    greeting: "Stance is everything. Bad habits can be unlearned, \
+                        for a fee."
+                        .into(),
+                    respec_cost: 10,
                 },
+                TileEvent::Merchant(Merchant {
+                    name: "Trader Oswin".into(),
+                    greeting: "Potions, steel, a bit of leather. Coin up front, \
+                        and I'll buy whatever you drag back from the wilds."
+                        .into(),
+                    stock: vec![
+                        small_potion,
+                        health_potion,
+                        Item {
+                            name: "Leather Cap".into(),
+                            description: "Boiled leather, snug and light. +1 def.".into(),
+                            item_type: ItemType::Armor,
+                            value: 1,
+                            slot: Some(EquipSlot::Head),
+                            price: 25,
+                            ..Default::default()
+                        },
+                        Item {
+                            name: "Leather Jerkin".into(),
+                            description: "A padded jerkin that turns a glancing blow. +2 def."
+                                .into(),
+                            item_type: ItemType::Armor,
+                            value: 2,
+                            slot: Some(EquipSlot::Body),
+                            price: 45,
+                            ..Default::default()
+                        },
+                        Item {
+                            name: "Iron Sword".into(),
+                            description: "Plain, well-balanced village steel.".into(),
+                            item_type: ItemType::Weapon,
+                            value: 6,
+                            price: 70,
+                            ..Default::default()
+                        },
+                    ],
+                    buy_rate: 0.5,
+                }),
             ],
         },
         // 1 — Forest Path
@@ -99,15 +447,42 @@ pub fn build_world() -> Vec<Tile> {
                     hp: 15,
                     damage_dice: Dice::new(1, 6, 1), // 1d6+1
                     exp_reward: 15,
-                    loot: None,
+                    loot: vec![LootTable::new(
+                        0.6,
+                        vec![LootEntry::stack(
+                            Item {
+                                name: "Wolf Pelt".into(),
+                                description: "A coarse grey pelt. The trader will want it.".into(),
+                                item_type: ItemType::Misc,
+                                price: 6,
+                                ..Default::default()
+                            },
+                            1,
+                            2,
+                            1,
+                        )],
+                    )],
                     is_boss: false,
+                    armor: 11,
+                    accuracy: 3,
+                    inflicts: Some(Affliction::new(StatusKind::Bleed, 3, 1, 0.3)),
+                    behavior: Behavior::Aggressive,
+                    phases: vec![],
+                    respawn: Respawn::AfterMinutes(3),
+                    gold: None,
                 }),
-                TileEvent::ItemPickup(Item {
-                    name: "Forest Herb".into(),
-                    description: "A fragrant herb. Restores 8 HP.".into(),
-                    item_type: ItemType::Potion,
-                    value: 8,
-                }),
+                TileEvent::ItemPickup {
+                    item: Item {
+                        name: "Forest Herb".into(),
+                        description: "A fragrant herb. Restores 8 HP, then 2 HP a turn.".into(),
+                        item_type: ItemType::Potion,
+                        value: 8,
+                        effect: Some(Affliction::new(StatusKind::Regeneration, 3, 2, 1.0)),
+                        price: 8,
+                        ..Default::default()
+                    },
+                    respawn: Respawn::OnRest,
+                },
             ],
         },
         // 2 — River Crossing (peaceful, low chance)
@@ -121,12 +496,17 @@ pub fn build_world() -> Vec<Tile> {
             connections: vec![0, 5],
             encounter_chance: 0.15,
             events: vec![
-                TileEvent::ItemPickup(Item {
-                    name: "Sturdy Sword".into(),
-                    description: "A solid blade wedged between the rocks.".into(),
-                    item_type: ItemType::Weapon,
-                    value: 5,
-                }),
+                TileEvent::ItemPickup {
+                    item: Item {
+                        name: "Sturdy Sword".into(),
+                        description: "A solid blade wedged between the rocks.".into(),
+                        item_type: ItemType::Weapon,
+                        value: 5,
+                        price: 40,
+                        ..Default::default()
+                    },
+                    respawn: Respawn::Never,
+                },
                 TileEvent::Nothing,
             ],
         },
@@ -146,20 +526,58 @@ pub fn build_world() -> Vec<Tile> {
                     hp: 12,
                     damage_dice: Dice::new(1, 4, 1), // 1d4+1
                     exp_reward: 12,
-                    loot: Some(Item {
-                        name: "Goblin Dagger".into(),
-                        description: "A crude but sharp dagger.".into(),
-                        item_type: ItemType::Weapon,
-                        value: 4,
-                    }),
+                    loot: vec![LootTable::new(
+                        0.7,
+                        vec![
+                            LootEntry::generated(
+                                Item {
+                                    name: "Goblin Dagger".into(),
+                                    description: "A crude but sharp dagger. Its jagged edge can cause bleeding.".into(),
+                                    item_type: ItemType::Weapon,
+                                    value: 4,
+                                    effect: Some(Affliction::new(StatusKind::Bleed, 2, 1, 0.25)),
+                                    price: 30,
+                                    rarity: Rarity::Uncommon,
+                                    ..Default::default()
+                                },
+                                2,
+                            ),
+                            LootEntry::table(potions.clone(), 3),
+                        ],
+                    )],
                     is_boss: false,
+                    armor: 12,
+                    accuracy: 2,
+                    inflicts: None,
+                    behavior: Behavior::Cowardly,
+                    phases: vec![],
+                    respawn: Respawn::AfterMinutes(5),
+                    gold: Some(Dice::new(1, 6, 0)), // 1d6
                 }),
-                TileEvent::ItemPickup(Item {
-                    name: "Torch".into(),
-                    description: "A flickering torch. Might be useful.".into(),
-                    item_type: ItemType::Misc,
-                    value: 0,
-                }),
+                TileEvent::ItemPickup {
+                    item: Item {
+                        name: "Miner's Helmet".into(),
+                        description: "A dented iron helmet left behind by the miners. +1 def."
+                            .into(),
+                        item_type: ItemType::Armor,
+                        value: 1,
+                        slot: Some(EquipSlot::Head),
+                        price: 20,
+                        ..Default::default()
+                    },
+                    respawn: Respawn::Never,
+                },
+                TileEvent::ItemPickup {
+                    item: Item {
+                        name: "Torch".into(),
+                        description: "A flickering torch. Might be useful.".into(),
+                        item_type: ItemType::Misc,
+                        value: 0,
+                        price: 2,
+                        ..Default::default()
+                    },
+                    respawn: Respawn::Never,
+                },
             ],
         },
         // 4 — Mossy Clearing
@@ -179,13 +597,44 @@ pub fn build_world() -> Vec<Tile> {
                     hp: 18,
                     damage_dice: Dice::new(1, 8, 1), // 1d8+1
                     exp_reward: 20,
-                    loot: Some(Item {
-                        name: "Spider Silk".into(),
-                        description: "Tough, shimmering silk. Could fetch a price.".into(),
-                        item_type: ItemType::Misc,
-                        value: 0,
-                    }),
+                    loot: vec![LootTable::new(
+                        1.0,
+                        vec![
+                            LootEntry::stack(
+                                Item {
+                                    name: "Spider Silk".into(),
+                                    description: "Tough, shimmering silk. Could fetch a price.".into(),
+                                    item_type: ItemType::Misc,
+                                    value: 0,
+                                    price: 15,
+                                    ..Default::default()
+                                },
+                                1,
+                                3,
+                                4,
+                            ),
+                            LootEntry::item(
+                                Item {
+                                    name: "Venom Sac".into(),
+                                    description: "A swollen gland, still dripping. Alchemists pay well."
+                                        .into(),
+                                    item_type: ItemType::Misc,
+                                    price: 35,
+                                    rarity: Rarity::Uncommon,
+                                    ..Default::default()
+                                },
+                                1,
+                            ),
+                        ],
+                    )],
                     is_boss: false,
+                    armor: 12,
+                    accuracy: 4,
+                    inflicts: Some(Affliction::new(StatusKind::Poison, 3, 2, 0.4)),
+                    behavior: Behavior::Aggressive,
+                    phases: vec![],
+                    respawn: Respawn::OnRest,
+                    gold: None,
                 }),
             ],
         },
@@ -205,13 +654,35 @@ pub fn build_world() -> Vec<Tile> {
                     hp: 20,
                     damage_dice: Dice::new(1, 8, 2), // 1d8+2
                     exp_reward: 25,
-                    loot: Some(Item {
-                        name: "Health Potion".into(),
-                        description: "A red vial. Restores 15 HP.".into(),
-                        item_type: ItemType::Potion,
-                        value: 15,
-                    }),
+                    loot: vec![LootTable {
+                        chance: 1.0,
+                        rolls: 2,
+                        entries: vec![
+                            LootEntry::table(potions.clone(), 3),
+                            LootEntry::generated(
+                                Item {
+                                    name: "Bandit's Hood".into(),
+                                    description: "A dark hood lined with chain. +2 def.".into(),
+                                    item_type: ItemType::Armor,
+                                    value: 2,
+                                    slot: Some(EquipSlot::Head),
+                                    price: 40,
+                                    rarity: Rarity::Uncommon,
+                                    ..Default::default()
+                                },
+                                1,
+                            ),
+                            LootEntry::nothing(2),
+                        ],
+                    }],
                     is_boss: false,
+                    armor: 13,
+                    accuracy: 4,
+                    inflicts: Some(Affliction::new(StatusKind::Weakened, 2, 25, 0.25)),
+                    behavior: Behavior::Cautious,
+                    phases: vec![],
+                    respawn: Respawn::AfterMinutes(10),
+                    gold: Some(Dice::new(2, 6, 5)), // 2d6+5
                 }),
                 TileEvent::Nothing,
             ],
@@ -231,13 +702,53 @@ pub fn build_world() -> Vec<Tile> {
                 hp: 50,
                 damage_dice: Dice::new(2, 6, 3), // 2d6+3
                 exp_reward: 100,
-                loot: Some(Item {
-                    name: "Troll's Greataxe".into(),
-                    description: "A massive axe pulsing with dark energy.".into(),
-                    item_type: ItemType::Weapon,
-                    value: 10,
-                }),
+                loot: vec![
+                    LootTable::guaranteed(Item {
+                        name: "Troll's Greataxe".into(),
+                        description: "A massive axe pulsing with dark energy.".into(),
+                        item_type: ItemType::Weapon,
+                        value: 10,
+                        effect: Some(Affliction::new(StatusKind::Bleed, 3, 2, 0.3)),
+                        price: 150,
+                        rarity: Rarity::Epic,
+                        ..Default::default()
+                    }),
+                    LootTable {
+                        rolls: 2,
+                        ..LootTable::new(1.0, vec![LootEntry::table(potions.clone(), 1)])
+                    },
+                ],
                 is_boss: true,
+                armor: 12,
+                accuracy: 5,
+                inflicts: Some(Affliction::new(StatusKind::Stun, 1, 0, 0.2)),
+                behavior: Behavior::Charger,
+                phases: vec![
+                    BossPhase {
+                        name: "Enraged".into(),
+                        trigger: PhaseTrigger::HpBelow(0.5),
+                        narration: "The Cave Troll bellows in fury! Its roar echoes \
+                            through the tunnels and a swarm of bats pours from the dark."
+                            .into(),
+                        damage_dice: Some(Dice::new(2, 8, 3)), // 2d8+3
+                        behavior: Some(Behavior::Aggressive),
+                        minions: vec![cave_bat.clone(), cave_bat],
+                        arena_effect: None,
+                    },
+                    BossPhase {
+                        name: "Cave-in".into(),
+                        trigger: PhaseTrigger::Turn(8),
+                        narration: "The cavern shudders under the troll's blows. Dust \
+                            and falling rock fill the air, choking you."
+                            .into(),
+                        damage_dice: None,
+                        behavior: Some(Behavior::Charger),
+                        minions: vec![],
+                        arena_effect: Some(StatusEffect::new(StatusKind::Weakened, 99, 20)),
+                    },
+                ],
+                respawn: Respawn::Never,
+                gold: Some(Dice::new(5, 10, 0)), // 5d10
             })],
         },
         // 7 — Ancient Ruins
@@ -256,13 +767,44 @@ pub fn build_world() -> Vec<Tile> {
                     hp: 22,
                     damage_dice: Dice::new(1, 10, 2), // 1d10+2
                     exp_reward: 30,
-                    loot: Some(Item {
-                        name: "Bone Shield".into(),
-                        description: "A shield fashioned from ancient bones. +3 def.".into(),
-                        item_type: ItemType::Misc,
-                        value: 3,
-                    }),
+                    loot: vec![LootTable::new(
+                        0.8,
+                        vec![
+                            LootEntry::generated(
+                                Item {
+                                    name: "Bone Shield".into(),
+                                    description: "A shield fashioned from ancient bones. +3 def.".into(),
+                                    item_type: ItemType::Armor,
+                                    value: 3,
+                                    slot: Some(EquipSlot::OffHand),
+                                    price: 50,
+                                    rarity: Rarity::Rare,
+                                    ..Default::default()
+                                },
+                                1,
+                            ),
+                            LootEntry::stack(
+                                Item {
+                                    name: "Bone Fragment".into(),
+                                    description: "Old, brittle bone etched with runes.".into(),
+                                    item_type: ItemType::Misc,
+                                    price: 4,
+                                    ..Default::default()
+                                },
+                                1,
+                                3,
+                                3,
+                            ),
+                        ],
+                    )],
                     is_boss: false,
+                    armor: 14,
+                    accuracy: 4,
+                    inflicts: Some(Affliction::new(StatusKind::Bleed, 2, 2, 0.25)),
+                    behavior: Behavior::Cautious,
+                    phases: vec![],
+                    respawn: Respawn::OnRest,
+                    gold: Some(Dice::new(1, 10, 0)), // 1d10
                 }),
                 TileEvent::Nothing,
             ],
@@ -283,16 +825,387 @@ pub fn build_world() -> Vec<Tile> {
                     hp: 60,
                     damage_dice: Dice::new(2, 8, 3), // 2d8+3
                     exp_reward: 150,
-                    loot: Some(Item {
-                        name: "Guardian's Amulet".into(),
-                        description: "A radiant amulet. You feel its power.".into(),
-                        item_type: ItemType::Misc,
-                        value: 0,
-                    }),
+                    loot: vec![
+                        LootTable::guaranteed(Item {
+                            name: "Guardian's Amulet".into(),
+                            description: "A radiant amulet. You feel its power.".into(),
+                            item_type: ItemType::Trinket,
+                            value: 0,
+                            bonus: StatBonus {
+                                attack: 2,
+                                defense: 1,
+                                max_hp: 10,
+                            },
+                            price: 200,
+                            rarity: Rarity::Legendary,
+                            ..Default::default()
+                        }),
+                        LootTable::new(1.0, vec![LootEntry::table(potions.clone(), 1)]),
+                    ],
                     is_boss: true,
+                    armor: 15,
+                    accuracy: 6,
+                    inflicts: Some(Affliction::new(StatusKind::Burn, 3, 3, 0.35)),
+                    behavior: Behavior::Healer,
+                    phases: vec![
+                        BossPhase {
+                            name: "Awakened".into(),
+                            trigger: PhaseTrigger::HpBelow(0.6),
+                            narration: "The runes on the Guardian's armor blaze white. \
+                                The shrine floor ignites and spectral wisps rise to \
+                                defend their master."
+                                .into(),
+                            damage_dice: None,
+                            behavior: Some(Behavior::Aggressive),
+                            minions: vec![spectral_wisp.clone(), spectral_wisp],
+                            arena_effect: Some(StatusEffect::new(StatusKind::Burn, 99, 1)),
+                        },
+                        BossPhase {
+                            name: "Last Stand".into(),
+                            trigger: PhaseTrigger::HpBelow(0.25),
+                            narration: "Cracks of light split the Guardian's shell. It \
+                                gathers the last of the shrine's power for a final \
+                                onslaught!"
+                                .into(),
+                            damage_dice: Some(Dice::new(3, 8, 3)), // 3d8+3
+                            behavior: Some(Behavior::Charger),
+                            minions: vec![],
+                            arena_effect: None,
+                        },
+                    ],
+                    respawn: Respawn::Never,
+                    gold: Some(Dice::new(6, 10, 0)), // 6d10
                 }),
                 TileEvent::Rest,
             ],
         },
     ]
 }
+
+// ── Hardcoded dialogue ───────────────────────────────────────────────
+
+fn elder_maren_dialogue() -> Dialogue {
+    use super::dialogue::{Condition, DialogueChoice, DialogueEffect, DialogueNode};
+    use super::player::ItemType;
+
+    let choice = |text: &str, next: Option<&str>| DialogueChoice {
+        text: text.into(),
+        conditions: vec![],
+        effects: vec![],
+        next: next.map(Into::into),
+    };
+    let back = || choice("Let me ask something else.", Some("greet"));
+    let farewell = || choice("Farewell.", None);
+
+    Dialogue {
+        start: "greet".into(),
+        nodes: vec![
+            DialogueNode {
+                id: "greet".into(),
+                text: "Welcome, hunter. Dark creatures stir in the mines to the north. \
+                    What brings you to an old woman's bench?"
+                    .into(),
+                choices: vec![
+                    choice("Any advice for a new hunter?", Some("advice")),
+                    DialogueChoice {
+                        conditions: vec![Condition::NotFlag("elder_gift".into())],
+                        effects: vec![
+                            DialogueEffect::GiveItem(Item {
+                                name: "Small Potion".into(),
+                                description: "Restores 10 HP.".into(),
+                                item_type: ItemType::Potion,
+                                value: 10,
+                                price: 10,
+                                quantity: 2,
+                                ..Default::default()
+                            }),
+                            DialogueEffect::SetFlag("elder_gift".into()),
+                        ],
+                        ..choice("I'm short on supplies.", Some("gift"))
+                    },
+                    DialogueChoice {
+                        conditions: vec![Condition::QuestNotStarted("wolf_cull".into())],
+                        ..choice("Is there work for me?", Some("work"))
+                    },
+                    DialogueChoice {
+                        conditions: vec![
+                            Condition::QuestCompleted("wolf_cull".into()),
+                            Condition::QuestNotStarted("silk_for_elder".into()),
+                        ],
+                        ..choice("Anything else I can do?", Some("silk"))
+                    },
+                    DialogueChoice {
+                        conditions: vec![Condition::QuestReady("silk_for_elder".into())],
+                        effects: vec![DialogueEffect::CompleteQuest("silk_for_elder".into())],
+                        ..choice("I brought the spider silk.", Some("silk_done"))
+                    },
+                    DialogueChoice {
+                        conditions: vec![
+                            Condition::MinLevel(3),
+                            Condition::NotFlag("shrine_told".into()),
+                        ],
+                        ..choice("What lies beyond the ruins?", Some("shrine"))
+                    },
+                    DialogueChoice {
+                        conditions: vec![
+                            Condition::BossDefeated("Cave Troll".into()),
+                            Condition::NotFlag("troll_thanks".into()),
+                        ],
+                        effects: vec![
+                            DialogueEffect::Heal(0),
+                            DialogueEffect::GiveGold(50),
+                            DialogueEffect::SetFlag("troll_thanks".into()),
+                        ],
+                        ..choice("The Cave Troll is dead.", Some("troll"))
+                    },
+                    farewell(),
+                ],
+            },
+            DialogueNode {
+                id: "advice".into(),
+                text: "Watch what a beast is about to do — a troll winding up a blow \
+                    is a troll you should be guarding against. And rest here \
+                    whenever you can."
+                    .into(),
+                choices: vec![back(), farewell()],
+            },
+            DialogueNode {
+                id: "gift".into(),
+                text: "Here, take these. My knees are too old for the forest anyway."
+                    .into(),
+                choices: vec![back(), farewell()],
+            },
+            DialogueNode {
+                id: "work".into(),
+                text: "Wolves have been harrying the woodcutters on the forest path. \
+                    Thin the pack and you'll have the village's thanks."
+                    .into(),
+                choices: vec![
+                    DialogueChoice {
+                        effects: vec![DialogueEffect::StartQuest("wolf_cull".into())],
+                        ..choice("I'll see to it.", Some("accepted"))
+                    },
+                    choice("Not right now.", Some("greet")),
+                ],
+            },
+            DialogueNode {
+                id: "accepted".into(),
+                text: "Good. Be careful out there, and come back in one piece.".into(),
+                choices: vec![back(), farewell()],
+            },
+            DialogueNode {
+                id: "silk".into(),
+                text: "My shawl is more hole than wool. Spider silk from the mossy \
+                    clearing would mend it — two skeins should do."
+                    .into(),
+                choices: vec![
+                    DialogueChoice {
+                        effects: vec![DialogueEffect::StartQuest("silk_for_elder".into())],
+                        ..choice("I'll bring you some.", Some("accepted"))
+                    },
+                    choice("Not right now.", Some("greet")),
+                ],
+            },
+            DialogueNode {
+                id: "silk_done".into(),
+                text: "Oh, it's beautiful. Here — this charm has kept me safe for \
+                    sixty winters. Your turn now."
+                    .into(),
+                choices: vec![back(), farewell()],
+            },
+            DialogueNode {
+                id: "shrine".into(),
+                text: "You've grown strong enough to hear it. Past the ancient ruins \
+                    a shrine hides in the rock, and its guardian has never slept. \
+                    Few who find it come back."
+                    .into(),
+                choices: vec![DialogueChoice {
+                    effects: vec![
+                        DialogueEffect::RevealSecret(
+                            "A hidden shrine lies north of the Ancient Ruins.".into(),
+                        ),
+                        DialogueEffect::SetFlag("shrine_told".into()),
+                        DialogueEffect::StartQuest("find_shrine".into()),
+                    ],
+                    ..choice("I'll find it.", Some("greet"))
+                }],
+            },
+            DialogueNode {
+                id: "troll".into(),
+                text: "Then the mines are ours again! Sit, let me tend those wounds — \
+                    and take this purse from the village."
+                    .into(),
+                choices: vec![back(), farewell()],
+            },
+        ],
+    }
+}
+
+// ── Hardcoded quests ─────────────────────────────────────────────────
+
+pub fn build_quests() -> Vec<Quest> {
+    use super::loot::Rarity;
+    use super::player::{ItemType, StatBonus};
+    use super::quest::{Objective, QuestReward};
+
+    vec![
+        Quest {
+            id: "wolf_cull".into(),
+            title: "Thin the Pack".into(),
+            description: "Wolves are harrying the woodcutters on the Forest Path. \
+                Elder Maren wants their numbers cut down."
+                .into(),
+            objectives: vec![Objective::Kill {
+                enemy: "Wild Wolf".into(),
+                count: 3,
+            }],
+            reward: QuestReward {
+                exp: 40,
+                gold: 30,
+                items: vec![],
+            },
+            turn_in: None,
+        },
+        Quest {
+            id: "silk_for_elder".into(),
+            title: "Silk for the Elder".into(),
+            description: "Elder Maren needs spider silk to mend her shawl. Giant \
+                spiders lair in the Mossy Clearing."
+                .into(),
+            objectives: vec![Objective::Collect {
+                item: "Spider Silk".into(),
+                count: 2,
+            }],
+            reward: QuestReward {
+                exp: 60,
+                gold: 20,
+                items: vec![Item {
+                    name: "Elder's Charm".into(),
+                    description: "A worn wooden charm on a leather cord.".into(),
+                    item_type: ItemType::Trinket,
+                    bonus: StatBonus {
+                        attack: 1,
+                        defense: 0,
+                        max_hp: 5,
+                    },
+                    price: 40,
+                    rarity: Rarity::Uncommon,
+                    ..Default::default()
+                }],
+            },
+            turn_in: Some("Elder Maren".into()),
+        },
+        Quest {
+            id: "find_shrine".into(),
+            title: "The Hidden Shrine".into(),
+            description: "Somewhere past the Ancient Ruins a shrine hides in the rock, \
+                watched by a guardian that never sleeps."
+                .into(),
+            objectives: vec![Objective::Reach {
+                tile: 8,
+                name: "Hidden Shrine".into(),
+            }],
+            reward: QuestReward {
+                exp: 50,
+                ..Default::default()
+            },
+            turn_in: None,
+        },
+    ]
+}
+
+// ── Hardcoded recipes ────────────────────────────────────────────────
+
+pub fn build_recipes() -> Vec<Recipe> {
+    use super::loot::Rarity;
+    use super::player::{EquipSlot, ItemType, StatBonus};
+    use super::status::StatusKind;
+
+    let ingredient = |name: &str, quantity: u32| Ingredient {
+        name: name.into(),
+        quantity,
+    };
+
+    vec![
+        Recipe {
+            ingredients: vec![ingredient("Forest Herb", 3)],
+            output: Item {
+                name: "Health Potion".into(),
+                description: "A red vial. Restores 15 HP.".into(),
+                item_type: ItemType::Potion,
+                value: 15,
+                price: 25,
+                ..Default::default()
+            },
+            starts_known: true,
+        },
+        Recipe {
+            ingredients: vec![ingredient("Wolf Pelt", 3)],
+            output: Item {
+                name: "Wolfhide Jerkin".into(),
+                description: "Stitched pelts, warm and tough. +2 def, +4 max HP.".into(),
+                item_type: ItemType::Armor,
+                value: 2,
+                slot: Some(EquipSlot::Body),
+                bonus: StatBonus {
+                    max_hp: 4,
+                    ..Default::default()
+                },
+                price: 50,
+                rarity: Rarity::Uncommon,
+                ..Default::default()
+            },
+            starts_known: false,
+        },
+        Recipe {
+            ingredients: vec![ingredient("Spider Silk", 1), ingredient("Bone Shield", 1)],
+            output: Item {
+                name: "Silken Buckler".into(),
+                description: "A bone shield bound in layers of spider silk. +4 def, +5 max HP."
+                    .into(),
+                item_type: ItemType::Armor,
+                value: 4,
+                slot: Some(EquipSlot::OffHand),
+                bonus: StatBonus {
+                    max_hp: 5,
+                    ..Default::default()
+                },
+                price: 90,
+                rarity: Rarity::Rare,
+                ..Default::default()
+            },
+            starts_known: false,
+        },
+        Recipe {
+            ingredients: vec![ingredient("Bone Fragment", 3), ingredient("Torch", 1)],
+            output: Item {
+                name: "Bone Charm".into(),
+                description: "Rune-etched bone, smoked over a torch. It hums faintly.".into(),
+                item_type: ItemType::Trinket,
+                bonus: StatBonus {
+                    defense: 1,
+                    max_hp: 3,
+                    ..Default::default()
+                },
+                price: 35,
+                rarity: Rarity::Uncommon,
+                ..Default::default()
+            },
+            starts_known: false,
+        },
+        Recipe {
+            ingredients: vec![ingredient("Venom Sac", 1), ingredient("Small Potion", 1)],
+            output: Item {
+                name: "Venom Draught".into(),
+                description: "Foul but bracing. Restores 12 HP, then 3 HP a turn.".into(),
+                item_type: ItemType::Potion,
+                value: 12,
+                effect: Some(Affliction::new(StatusKind::Regeneration, 3, 3, 1.0)),
+                price: 30,
+                rarity: Rarity::Uncommon,
+                ..Default::default()
+            },
+            starts_known: false,
+        },
+    ]
+}
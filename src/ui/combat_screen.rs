@@ -1,7 +1,8 @@
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, Gauge, List, ListItem, Paragraph};
+use ratatui::widgets::{Block, BorderType, Borders, Gauge, List, ListItem, Paragraph};
 
 use crate::game::combat::{CombatAction, CombatPhase};
+use crate::game::status::{StatusEffects, StatusKind};
 use crate::game::GameState;
 
 pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
@@ -12,7 +13,8 @@ pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(5), // enemy info
+            Constraint::Length(4 + combat.minions.len() as u16), // enemy info + minions
+            Constraint::Length(3), // player info
             Constraint::Min(4),   // combat log + actions
             Constraint::Length(1), // hint
             Constraint::Length(8), // message log
@@ -25,20 +27,32 @@ pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
     } else {
         0.0
     };
-    let enemy_color = if combat.is_boss {
-        Color::Magenta
+    // Bosses escalate from magenta through to red as their phases advance
+    let enemy_color = match (combat.is_boss, combat.entered_phases.len()) {
+        (false, _) => Color::Red,
+        (true, 0) => Color::Magenta,
+        (true, 1) => Color::LightRed,
+        (true, _) => Color::Red,
+    };
+    let enemy_title = match combat.phase_name() {
+        Some(phase) => format!(" ⚔ {} — {} ", combat.enemy_name, phase),
+        None => format!(" ⚔ {} ", combat.enemy_name),
+    };
+    let border_type = if combat.current_phase.is_some() {
+        BorderType::Thick
     } else {
-        Color::Red
+        BorderType::Plain
     };
 
     let enemy_block = Block::default()
-        .title(format!(" ⚔ {} ", combat.enemy_name))
+        .title(enemy_title)
         .title_style(
             Style::default()
                 .fg(enemy_color)
                 .add_modifier(Modifier::BOLD),
         )
         .borders(Borders::ALL)
+        .border_type(border_type)
         .border_style(Style::default().fg(enemy_color));
 
     let enemy_inner = enemy_block.inner(chunks[0]);
@@ -46,7 +60,7 @@ pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
 
     let enemy_rows = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Length(1), Constraint::Length(1)])
+        .constraints(vec![Constraint::Length(1); 2 + combat.minions.len()])
         .split(enemy_inner);
 
     let hp_gauge = Gauge::default()
@@ -56,24 +70,80 @@ pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
             "HP: {}/{}",
             combat.enemy_hp, combat.enemy_max_hp
         ));
-    frame.render_widget(hp_gauge, enemy_rows[0]);
+    draw_gauge_with_effects(frame, enemy_rows[0], hp_gauge, &combat.enemy_effects);
 
-    let enemy_stats = Paragraph::new(format!("ATK: {}", combat.enemy_damage_dice))
-        .style(Style::default().fg(Color::DarkGray));
+    let intent_style = if combat.intent.is_threatening() {
+        Style::default().fg(Color::LightRed).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Yellow)
+    };
+    let enemy_stats = Paragraph::new(Line::from(vec![
+        Span::styled(
+            format!(
+                "ATK {} (+{} to hit)  AC {}",
+                combat.enemy_damage_summary,
+                combat.enemy_accuracy,
+                combat.enemy_armor
+            ),
+            Style::default().fg(Color::DarkGray),
+        ),
+        Span::raw("  "),
+        Span::styled(
+            format!("⚠ It {}", combat.intent.telegraph()),
+            intent_style,
+        ),
+    ]));
     frame.render_widget(enemy_stats, enemy_rows[1]);
 
+    // Minions
+    for (minion, row) in combat.minions.iter().zip(enemy_rows.iter().skip(2)) {
+        let ratio = if minion.max_hp > 0 {
+            minion.hp as f64 / minion.max_hp as f64
+        } else {
+            0.0
+        };
+        let gauge = Gauge::default()
+            .gauge_style(Style::default().fg(Color::LightMagenta))
+            .ratio(ratio.clamp(0.0, 1.0))
+            .label(format!("{} {}/{}", minion.name, minion.hp, minion.max_hp));
+        frame.render_widget(gauge, *row);
+    }
+
+    // Player info
+    let player_block = Block::default()
+        .title(format!(
+            " ♥ {} — ST {}/{} ",
+            game.player.name, game.player.stamina, game.player.max_stamina
+        ))
+        .title_style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Green));
+    let player_inner = player_block.inner(chunks[1]);
+    frame.render_widget(player_block, chunks[1]);
+
+    let player_ratio = if game.player.total_max_hp() > 0 {
+        game.player.hp as f64 / game.player.total_max_hp() as f64
+    } else {
+        0.0
+    };
+    let player_gauge = Gauge::default()
+        .gauge_style(Style::default().fg(Color::Green))
+        .ratio(player_ratio.clamp(0.0, 1.0))
+        .label(format!("HP: {}/{}", game.player.hp, game.player.total_max_hp()));
+    draw_gauge_with_effects(frame, player_inner, player_gauge, &combat.player_effects);
+
     // Combat log + actions side by side
     let mid_cols = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
-        .split(chunks[1]);
+        .split(chunks[2]);
 
     // Combat log (left)
     let visible = mid_cols[0].height.saturating_sub(2) as usize;
     let start = combat.log.len().saturating_sub(visible);
     let log_lines: Vec<ListItem> = combat.log[start..]
         .iter()
-        .map(|s| ListItem::new(s.as_str()).style(Style::default().fg(Color::White)))
+        .map(|s| ListItem::new(Line::from(super::markup_spans(s, Style::default().fg(Color::White)))))
         .collect();
     let combat_log = List::new(log_lines).block(
         Block::default()
@@ -84,14 +154,26 @@ pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
 
     // Actions (right)
     if combat.phase == CombatPhase::PlayerChoosing {
-        let actions: Vec<ListItem> = CombatAction::ALL
+        let actions: Vec<ListItem> = combat
+            .actions(&game.player)
             .iter()
             .enumerate()
             .map(|(i, action)| {
+                let mut blocked = false;
                 let label = match action {
                     CombatAction::UsePotion => {
                         format!("  {} ({})", action.label(), game.player.potion_count())
                     }
+                    CombatAction::Ability(ability) => {
+                        blocked = combat.ability_blocked(*ability, &game.player).is_some();
+                        match combat.cooldown(*ability) {
+                            0 => format!("  {} ({} ST)", action.label(), ability.cost()),
+                            turns => format!("  {} (ready in {})", action.label(), turns),
+                        }
+                    }
+                    CombatAction::Flee => {
+                        format!("  {} ({:.0}%)", action.label(), combat.flee_odds * 100.0)
+                    }
                     _ => format!("  {}", action.label()),
                 };
                 let item = ListItem::new(label);
@@ -102,6 +184,8 @@ pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
                             .bg(Color::Yellow)
                             .add_modifier(Modifier::BOLD),
                     )
+                } else if blocked {
+                    item.style(Style::default().fg(Color::DarkGray))
                 } else {
                     item.style(Style::default().fg(Color::White))
                 }
@@ -119,6 +203,7 @@ pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
             CombatPhase::Victory => "Victory! Press any key...",
             CombatPhase::Defeat => "Defeated... Press any key...",
             CombatPhase::Fled => "Escaped! Press any key...",
+            CombatPhase::EnemyFled => "It got away... Press any key...",
             _ => "",
         };
         let p = Paragraph::new(msg)
@@ -130,8 +215,42 @@ pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
     // Hint
     let hint = Paragraph::new(" ↑↓/jk: select • Enter: confirm")
         .style(Style::default().fg(Color::DarkGray));
-    frame.render_widget(hint, chunks[2]);
+    frame.render_widget(hint, chunks[3]);
 
     // Message log
-    super::draw_log(frame, chunks[3], game);
+    super::draw_log(frame, chunks[4], game);
+}
+
+/// HP gauge on the left, active status effect icons on the right.
+fn draw_gauge_with_effects(frame: &mut Frame, area: Rect, gauge: Gauge, effects: &StatusEffects) {
+    let spans: Vec<Span> = effects
+        .active
+        .iter()
+        .map(|e| {
+            Span::styled(
+                format!(" {}{}", e.kind.icon(), e.turns),
+                Style::default().fg(effect_color(e.kind)),
+            )
+        })
+        .collect();
+    let icons_width = spans.iter().map(|s| s.width() as u16).sum::<u16>() + 1;
+
+    let cols = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(10), Constraint::Length(icons_width)])
+        .split(area);
+
+    frame.render_widget(gauge, cols[0]);
+    frame.render_widget(Paragraph::new(Line::from(spans)), cols[1]);
+}
+
+fn effect_color(kind: StatusKind) -> Color {
+    match kind {
+        StatusKind::Poison => Color::LightGreen,
+        StatusKind::Bleed => Color::Red,
+        StatusKind::Stun => Color::Yellow,
+        StatusKind::Burn => Color::LightRed,
+        StatusKind::Regeneration => Color::Green,
+        StatusKind::Weakened => Color::Magenta,
+    }
 }
@@ -0,0 +1,110 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
+
+use crate::game::GameState;
+
+pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(8),    // recipes + details
+            Constraint::Length(1), // hint
+            Constraint::Length(8), // message log
+        ])
+        .split(area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+        .split(rows[0]);
+
+    // Left: recipe list; unknown ones stay hidden
+    let items: Vec<ListItem> = game
+        .recipes
+        .iter()
+        .enumerate()
+        .map(|(i, recipe)| {
+            let known = recipe.is_known(&game.player);
+            let craftable = known && recipe.missing(&game.player).is_empty();
+            let label = if known {
+                format!("  {} {}", if craftable { "✔" } else { " " }, recipe.name())
+            } else {
+                "    ???".into()
+            };
+            let style = if i == game.selected {
+                Style::default().fg(Color::Black).bg(Color::Cyan)
+            } else if craftable {
+                Style::default().fg(super::rarity_color(recipe.output.rarity))
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            ListItem::new(label).style(style)
+        })
+        .collect();
+
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Workbench "),
+    );
+    frame.render_widget(list, chunks[0]);
+
+    // Right: ingredients and what comes out
+    let mut lines = Vec::new();
+    match game.recipes.get(game.selected) {
+        Some(recipe) if recipe.is_known(&game.player) => {
+            lines.push(Line::from(Span::styled(
+                recipe.name(),
+                Style::default()
+                    .fg(super::rarity_color(recipe.output.rarity))
+                    .add_modifier(Modifier::BOLD),
+            )));
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Needs:",
+                Style::default().fg(Color::Cyan),
+            )));
+            for ingredient in &recipe.ingredients {
+                let have = game.player.count_item(&ingredient.name);
+                let color = if have >= ingredient.quantity {
+                    Color::Green
+                } else {
+                    Color::Red
+                };
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "  {} ×{}  ({}/{})",
+                        ingredient.name, ingredient.quantity, have, ingredient.quantity
+                    ),
+                    Style::default().fg(color),
+                )));
+            }
+            lines.push(Line::from(""));
+            lines.extend(recipe.output.details().into_iter().map(Line::from));
+        }
+        Some(_) => {
+            lines.push(Line::from(Span::styled(
+                "An unknown recipe.",
+                Style::default().fg(Color::DarkGray),
+            )));
+            lines.push(Line::from(""));
+            lines.push(Line::from(
+                "Bring every ingredient to a workbench to work it out.",
+            ));
+        }
+        None => {}
+    }
+
+    let details = Paragraph::new(lines)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL).title(" Recipe "));
+    frame.render_widget(details, chunks[1]);
+
+    // Hint
+    let hint = Paragraph::new(" ↑↓/jk: select • Enter: craft • Esc: leave")
+        .style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(hint, rows[1]);
+
+    // Message log
+    super::draw_log(frame, rows[2], game);
+}
@@ -0,0 +1,67 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
+
+use crate::game::GameState;
+
+pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
+    let Some(ref state) = game.dialogue else {
+        return;
+    };
+    let choices = state.available_choices(&game.player, &game.world_state, &game.quests);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),                           // speech
+            Constraint::Length(choices.len() as u16 + 2), // responses
+            Constraint::Length(1),                        // hint
+            Constraint::Length(8),                        // message log
+        ])
+        .split(area);
+
+    // NPC speech
+    let text = state
+        .current()
+        .map(|node| node.text.as_str())
+        .unwrap_or("...");
+    let speech = Paragraph::new(format!("\"{}\"", text))
+        .style(Style::default().fg(Color::White))
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow))
+                .title(format!(" {} ", state.npc)),
+        );
+    frame.render_widget(speech, rows[0]);
+
+    // Responses
+    let items: Vec<ListItem> = choices
+        .iter()
+        .enumerate()
+        .map(|(i, choice)| {
+            let marker = if choice.next.is_none() { "✕" } else { "›" };
+            let item = ListItem::new(format!("  {} {}", marker, choice.text));
+            if i == game.selected {
+                item.style(
+                    Style::default()
+                        .fg(Color::Black)
+                        .bg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                item.style(Style::default().fg(Color::White))
+            }
+        })
+        .collect();
+    let list = List::new(items).block(Block::default().borders(Borders::ALL).title(" You "));
+    frame.render_widget(list, rows[1]);
+
+    // Hint
+    let hint = Paragraph::new(" ↑↓/jk: select • Enter: respond • Esc: walk away")
+        .style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(hint, rows[2]);
+
+    // Message log
+    super::draw_log(frame, rows[3], game);
+}
@@ -72,7 +72,7 @@ pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
     frame.render_widget(list, chunks[2]);
 
     // Hint bar
-    let hint = Paragraph::new(" ↑↓/jk: select • Enter: confirm • i: inventory • q: quit")
+    let hint = Paragraph::new(" ↑↓/jk: select • Enter: confirm • i: inventory • l: quests • t: talents • s: stats • b: leaderboards • q: quit")
         .style(Style::default().fg(Color::DarkGray));
     frame.render_widget(hint, chunks[3]);
 }
@@ -2,6 +2,7 @@ use ratatui::prelude::*;
 use ratatui::widgets::Paragraph;
 
 use crate::game::GameState;
+use crate::game::difficulty::Difficulty;
 
 pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
     let chunks = Layout::default()
@@ -35,7 +36,15 @@ pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
     .alignment(Alignment::Center);
     frame.render_widget(info, chunks[2]);
 
-    let prompt = Paragraph::new("Press R to restart  •  Q to quit")
+    let prompt = match game.player.difficulty {
+        Difficulty::Casual => "Press R to wake in the village  •  Q to quit",
+        Difficulty::CorpseRun => "Press R to wake where you last rested  •  Q to quit",
+        Difficulty::Normal => "Press R to restart  •  Q to quit",
+        Difficulty::Hardcore => {
+            "Your name is carved on the memorial.  Press R to return to the title  •  Q to quit"
+        }
+    };
+    let prompt = Paragraph::new(prompt)
         .style(Style::default().fg(Color::Yellow))
         .alignment(Alignment::Center);
     frame.render_widget(prompt, chunks[3]);
@@ -77,7 +86,7 @@ pub fn draw_victory(frame: &mut Frame, area: Rect, game: &GameState) {
     let stats = Paragraph::new(format!(
         "Level: {}  |  Total EXP: {}  |  Items: {}",
         game.player.level,
-        game.player.exp + game.player.exp_to_next * (game.player.level - 1),
+        game.player.exp + game.progression.total_exp(game.player.level),
         game.player.inventory.len(),
     ))
     .style(Style::default().fg(Color::Cyan))
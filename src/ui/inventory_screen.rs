@@ -1,14 +1,15 @@
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap};
 
-use crate::game::player::ItemType;
-use crate::game::GameState;
+use crate::game::player::{BAG_CAPACITY, EquipSlot, ItemType};
+use crate::game::{GameState, ItemPopup};
 
 pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
     let rows = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Min(8),   // stats + inventory
+            Constraint::Length(1), // hint
             Constraint::Length(8), // message log
         ])
         .split(area);
@@ -27,17 +28,21 @@ pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
                 .add_modifier(Modifier::BOLD),
         )),
         Line::from(""),
+        Line::from(format!("  Class:   {}", game.player.class_label())),
         Line::from(format!(
             "  HP:      {}/{}",
-            game.player.hp, game.player.max_hp
+            game.player.hp, game.player.total_max_hp()
         )),
         Line::from(format!("  Level:   {}", game.player.level)),
+        Line::from(format!("  EXP:     {}", super::exp_label(game))),
+        Line::from(format!("  Attack:  {}", game.player.total_attack())),
+        Line::from(format!("  Defense: {}", game.player.total_defense())),
+        Line::from(format!("  Accuracy: +{}", game.player.accuracy())),
+        Line::from(format!("  Armor:   AC {}", game.player.armor_class())),
         Line::from(format!(
-            "  EXP:     {}/{}",
-            game.player.exp, game.player.exp_to_next
+            "  Stamina: {}/{}",
+            game.player.stamina, game.player.max_stamina
         )),
-        Line::from(format!("  Attack:  {}", game.player.total_attack())),
-        Line::from(format!("  Defense: {}", game.player.defense)),
         Line::from(""),
         Line::from(Span::styled(
             "  Equipment:",
@@ -45,21 +50,36 @@ pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
         )),
     ];
 
-    // List equipped weapon
+    // Equipment slots (selectable when focused)
     let mut lines = stats_text;
-    if let Some(weapon) = game
-        .player
-        .inventory
-        .iter()
-        .filter(|i| i.item_type == ItemType::Weapon)
-        .max_by_key(|i| i.value)
-    {
+    for (i, slot) in EquipSlot::ALL.iter().enumerate() {
+        let text = match game.player.equipment.get(*slot) {
+            Some(item) => format!("    {:<10} {} ({})", slot.label(), item.name, item.stat_summary()),
+            None => format!("    {:<10} (empty)", slot.label()),
+        };
+        let style = if game.equipment_focus && i == game.selected {
+            Style::default().fg(Color::Black).bg(Color::Cyan)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(Span::styled(text, style)));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "  Abilities:",
+        Style::default().fg(Color::Cyan),
+    )));
+    if game.player.abilities.is_empty() {
+        lines.push(Line::from("    (none yet)"));
+    }
+    for ability in &game.player.abilities {
         lines.push(Line::from(format!(
-            "    Weapon: {} (+{})",
-            weapon.name, weapon.value
+            "    {} ({} ST, {}t cd)",
+            ability.label(),
+            ability.cost(),
+            ability.cooldown()
         )));
-    } else {
-        lines.push(Line::from("    Weapon: (none)"));
     }
 
     let stats = Paragraph::new(lines).block(
@@ -70,31 +90,81 @@ pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
     frame.render_widget(stats, chunks[0]);
 
     // Right: inventory list
-    if game.player.inventory.is_empty() {
-        let empty = Paragraph::new("  Your pack is empty.")
-            .style(Style::default().fg(Color::DarkGray))
+    let mut title = format!(
+        " Inventory ({}/{}) ",
+        game.player.inventory.len(),
+        BAG_CAPACITY
+    );
+    if let Some(filter) = game.inventory_filter {
+        title.push_str(&format!("— {} ", filter.label()));
+    }
+    let bag_block = Block::default().borders(Borders::ALL).title(title);
+    let bag_area = match game.pending_pickup {
+        Some(ref pending) => {
+            let parts = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(3), Constraint::Min(3)])
+                .split(chunks[1]);
+            let banner = Paragraph::new(format!(
+                " Pack full! Drop something to take the {} (Esc: leave it)",
+                pending.item.display_name()
+            ))
+            .style(Style::default().fg(Color::Yellow))
+            .wrap(Wrap { trim: true })
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title(" Inventory "),
+                    .border_style(Style::default().fg(Color::Yellow)),
             );
-        frame.render_widget(empty, chunks[1]);
+            frame.render_widget(banner, parts[0]);
+            parts[1]
+        }
+        None => chunks[1],
+    };
+
+    let visible = game.visible_inventory();
+    if visible.is_empty() {
+        let message = if game.player.inventory.is_empty() {
+            "  Your pack is empty."
+        } else {
+            "  Nothing of that type."
+        };
+        let empty = Paragraph::new(message)
+            .style(Style::default().fg(Color::DarkGray))
+            .block(bag_block);
+        frame.render_widget(empty, bag_area);
     } else {
-        let items: Vec<ListItem> = game
-            .player
-            .inventory
+        let items: Vec<ListItem> = visible
             .iter()
+            .map(|&index| &game.player.inventory[index])
             .enumerate()
             .map(|(i, item)| {
                 let icon = match item.item_type {
                     ItemType::Weapon => "W",
+                    ItemType::Armor => "A",
+                    ItemType::Trinket => "T",
                     ItemType::Potion => "P",
                     ItemType::Key => "K",
                     ItemType::Misc => "*",
                 };
-                let label = format!("  [{}] {} — {}", icon, item.name, item.description);
-                let list_item = ListItem::new(label);
-                if i == game.selected {
+                let stats = item.stat_summary();
+                let rest = if stats.is_empty() {
+                    format!(" — {}", item.description)
+                } else {
+                    format!(" ({}) — {}", stats, item.description)
+                };
+                let selected = !game.equipment_focus && i == game.selected;
+                let name_style = if selected {
+                    Style::default().add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(super::rarity_color(item.rarity))
+                };
+                let list_item = ListItem::new(Line::from(vec![
+                    Span::raw(format!("  [{}] ", icon)),
+                    Span::styled(item.display_name(), name_style),
+                    Span::raw(rest),
+                ]));
+                if selected {
                     list_item.style(
                         Style::default()
                             .fg(Color::Black)
@@ -106,14 +176,87 @@ pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
             })
             .collect();
 
-        let list = List::new(items).block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(" Inventory "),
-        );
-        frame.render_widget(list, chunks[1]);
+        let list = List::new(items).block(bag_block);
+        frame.render_widget(list, bag_area);
+    }
+
+    if let Some(ref popup) = game.item_popup {
+        draw_item_popup(frame, rows[0], game, popup);
     }
 
+    // Hint
+    let hint = if game.equipment_focus {
+        " ↑↓/jk: select slot • Enter/u: unequip • Tab: bag • Esc/i: close"
+    } else {
+        " ↑↓/jk: select • Enter: actions • e: equip • s: sort • f: filter • Tab: equipment • Esc/i: close"
+    };
+    let hint = Paragraph::new(hint).style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(hint, rows[1]);
+
     // Message log
-    super::draw_log(frame, rows[1], game);
+    super::draw_log(frame, rows[2], game);
+}
+
+/// Action menu, drop confirmation or inspect view for the selected item.
+fn draw_item_popup(frame: &mut Frame, area: Rect, game: &GameState, popup: &ItemPopup) {
+    let Some(item) = game
+        .selected_bag_index()
+        .and_then(|index| game.player.inventory.get(index))
+    else {
+        return;
+    };
+
+    let (lines, height): (Vec<Line>, u16) = match popup {
+        ItemPopup::Actions(cursor) => {
+            let actions = item.actions();
+            let lines = actions
+                .iter()
+                .enumerate()
+                .map(|(i, action)| {
+                    let style = if i == *cursor {
+                        Style::default()
+                            .fg(Color::Black)
+                            .bg(Color::Yellow)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(Color::White)
+                    };
+                    Line::from(Span::styled(format!("  {}  ", action.label()), style))
+                })
+                .collect();
+            (lines, actions.len() as u16 + 2)
+        }
+        ItemPopup::ConfirmDrop => (
+            vec![
+                Line::from(format!("Drop the {}?", item.display_name())),
+                Line::from(""),
+                Line::from(Span::styled(
+                    "y: drop • n: keep",
+                    Style::default().fg(Color::DarkGray),
+                )),
+            ],
+            5,
+        ),
+        ItemPopup::Inspect => {
+            let mut lines: Vec<Line> = item.details().into_iter().map(Line::from).collect();
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Press any key",
+                Style::default().fg(Color::DarkGray),
+            )));
+            let height = lines.len() as u16 + 4;
+            (lines, height)
+        }
+    };
+
+    let popup_area = super::popup_area(44, height, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .title(format!(" {} ", item.name));
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(
+        Paragraph::new(lines).block(block).wrap(Wrap { trim: true }),
+        popup_area,
+    );
 }
@@ -0,0 +1,84 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Tabs};
+
+use crate::game::GameState;
+
+pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
+    let Some(ref board) = game.leaderboard else {
+        return;
+    };
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // tabs
+            Constraint::Min(5),    // rankings
+            Constraint::Length(1), // hint
+        ])
+        .split(area);
+
+    let titles: Vec<Line> = board
+        .boards
+        .iter()
+        .map(|(b, _)| Line::from(b.label()))
+        .collect();
+    let tabs = Tabs::new(titles)
+        .select(board.tab)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow))
+                .title(" Leaderboards "),
+        )
+        .style(Style::default().fg(Color::DarkGray))
+        .highlight_style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        );
+    frame.render_widget(tabs, rows[0]);
+
+    let Some((current, entries)) = board.current() else {
+        return;
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" {} ", current.label()));
+    if entries.is_empty() {
+        let text = Paragraph::new("\n  No hunter has made this board yet.")
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        frame.render_widget(text, rows[1]);
+    } else {
+        let items: Vec<ListItem> = entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let color = match i {
+                    0 => Color::Yellow,
+                    1 => Color::White,
+                    2 => Color::LightRed,
+                    _ => Color::Gray,
+                };
+                let marker = if entry.dead { " ✝" } else { "" };
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("  {:>2}. {:<16}", i + 1, entry.name),
+                        Style::default().fg(color).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!("{:<14}", entry.score),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::styled(entry.detail.clone(), Style::default().fg(Color::DarkGray)),
+                    Span::styled(marker, Style::default().fg(Color::Red)),
+                ]))
+            })
+            .collect();
+        frame.render_widget(List::new(items).block(block), rows[1]);
+    }
+
+    let hint = Paragraph::new(" ←→/hl: switch board • Esc: back • ✝ fell in hardcore")
+        .style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(hint, rows[2]);
+}
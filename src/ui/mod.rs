@@ -1,13 +1,23 @@
 mod combat_screen;
+mod crafting_screen;
+mod dialogue_screen;
 mod explore_screen;
 mod game_over_screen;
 mod inventory_screen;
+mod leaderboard_screen;
+mod quest_log_screen;
+mod records_screen;
+mod shop_screen;
+mod stats_screen;
+mod talents_screen;
 mod title_screen;
 
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, Gauge, Paragraph};
+use ratatui::widgets::{Block, Borders, Clear, Gauge, Paragraph};
 
-use crate::game::{GameState, Screen};
+use crate::game::difficulty::Difficulty;
+use crate::game::loot::Rarity;
+use crate::game::{GameState, LevelUpSummary, Screen};
 
 /// Main draw dispatcher — called each frame, pure rendering.
 pub fn draw(frame: &mut Frame, game: &GameState) {
@@ -15,13 +25,27 @@ pub fn draw(frame: &mut Frame, game: &GameState) {
 
     match game.screen {
         Screen::Title => title_screen::draw(frame, area, game),
+        Screen::Graveyard => records_screen::draw_graveyard(frame, area, game),
+        Screen::HallOfFame => records_screen::draw_hall_of_fame(frame, area, game),
         Screen::NameEntry => title_screen::draw_name_entry(frame, area, game),
+        Screen::ClassSelect => title_screen::draw_class_select(frame, area, game),
         Screen::Explore => draw_with_chrome(frame, area, game, explore_screen::draw),
         Screen::Combat => draw_with_chrome(frame, area, game, combat_screen::draw),
         Screen::Inventory => draw_with_chrome(frame, area, game, inventory_screen::draw),
+        Screen::Shop => draw_with_chrome(frame, area, game, shop_screen::draw),
+        Screen::Crafting => draw_with_chrome(frame, area, game, crafting_screen::draw),
+        Screen::Dialogue => draw_with_chrome(frame, area, game, dialogue_screen::draw),
+        Screen::QuestLog => draw_with_chrome(frame, area, game, quest_log_screen::draw),
+        Screen::Talents => draw_with_chrome(frame, area, game, talents_screen::draw),
+        Screen::Leaderboard => leaderboard_screen::draw(frame, area, game),
+        Screen::Stats => draw_with_chrome(frame, area, game, stats_screen::draw),
         Screen::GameOver => game_over_screen::draw(frame, area, game),
         Screen::Victory => game_over_screen::draw_victory(frame, area, game),
     }
+
+    if let Some(ref summary) = game.level_up {
+        draw_level_up(frame, area, summary);
+    }
 }
 
 /// Shared layout: header bar + main content (each screen draws log internally).
@@ -43,7 +67,7 @@ fn draw_with_chrome(
     content_fn(frame, chunks[1], game);
 }
 
-/// Top bar: game name, HP bar, level/exp.
+/// Top bar: game name, HP bar, level/exp, gold.
 fn draw_header(frame: &mut Frame, area: Rect, game: &GameState) {
     let cols = Layout::default()
         .direction(Direction::Horizontal)
@@ -51,18 +75,25 @@ fn draw_header(frame: &mut Frame, area: Rect, game: &GameState) {
             Constraint::Length(20), // title
             Constraint::Min(20),   // hp bar
             Constraint::Length(22), // level/exp
+            Constraint::Length(12), // gold
         ])
         .split(area);
 
     // Title
+    let difficulty = game.player.difficulty;
     let title = Paragraph::new("  HUNTER GAME")
         .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
-        .block(Block::default().borders(Borders::ALL));
+        .block(
+            Block::default().borders(Borders::ALL).title(Span::styled(
+                format!(" {} ", difficulty.label()),
+                Style::default().fg(difficulty_color(difficulty)),
+            )),
+        );
     frame.render_widget(title, cols[0]);
 
     // HP bar
-    let hp_ratio = if game.player.max_hp > 0 {
-        game.player.hp as f64 / game.player.max_hp as f64
+    let hp_ratio = if game.player.total_max_hp() > 0 {
+        game.player.hp as f64 / game.player.total_max_hp() as f64
     } else {
         0.0
     };
@@ -77,17 +108,105 @@ fn draw_header(frame: &mut Frame, area: Rect, game: &GameState) {
         .block(Block::default().title(" HP ").borders(Borders::ALL))
         .gauge_style(Style::default().fg(hp_color))
         .ratio(hp_ratio.clamp(0.0, 1.0))
-        .label(format!("{}/{}", game.player.hp, game.player.max_hp));
+        .label(format!("{}/{}", game.player.hp, game.player.total_max_hp()));
     frame.render_widget(hp_gauge, cols[1]);
 
     // Level / Exp
     let stats = Paragraph::new(format!(
-        " Lv.{} EXP {}/{}",
-        game.player.level, game.player.exp, game.player.exp_to_next
+        " Lv.{} EXP {}",
+        game.player.level,
+        exp_label(game)
     ))
     .style(Style::default().fg(Color::Cyan))
     .block(Block::default().borders(Borders::ALL));
     frame.render_widget(stats, cols[2]);
+
+    // Gold
+    let gold = Paragraph::new(format!(" {} g", game.player.gold))
+        .style(Style::default().fg(Color::Yellow))
+        .block(Block::default().borders(Borders::ALL));
+    frame.render_widget(gold, cols[3]);
+}
+
+/// "40/100", or "MAX" at the level cap.
+pub(crate) fn exp_label(game: &GameState) -> String {
+    if game.player.exp_to_next == 0 {
+        "MAX".into()
+    } else {
+        format!("{}/{}", game.player.exp, game.player.exp_to_next)
+    }
+}
+
+/// Popup over any screen summing up the levels just gained.
+fn draw_level_up(frame: &mut Frame, area: Rect, summary: &LevelUpSummary) {
+    let title = if summary.to > summary.from + 1 {
+        format!("Level {} → {}", summary.from, summary.to)
+    } else {
+        format!("Level {}", summary.to)
+    };
+    let mut lines = vec![
+        Line::from(Span::styled(
+            title,
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    for (value, label) in [
+        (summary.gains.max_hp, "Max HP"),
+        (summary.gains.attack, "Attack"),
+        (summary.gains.defense, "Defense"),
+        (summary.gains.stamina, "Stamina"),
+    ] {
+        if value != 0 {
+            lines.push(Line::from(Span::styled(
+                format!("  {:<8} {:+}", label, value),
+                Style::default().fg(Color::Green),
+            )));
+        }
+    }
+    if summary.attribute_points + summary.talent_points > 0 {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!(
+                "  +{} attribute, +{} talent points",
+                summary.attribute_points, summary.talent_points
+            ),
+            Style::default().fg(Color::Cyan),
+        )));
+    }
+    if !summary.abilities.is_empty() {
+        lines.push(Line::from(""));
+        for ability in &summary.abilities {
+            lines.push(Line::from(Span::styled(
+                format!("  New: {}", ability.label()),
+                Style::default().fg(Color::Cyan),
+            )));
+        }
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "HP and stamina restored. Press any key.",
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    let popup = popup_area(40, lines.len() as u16 + 2, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .title(" LEVEL UP! ");
+    frame.render_widget(Clear, popup);
+    frame.render_widget(Paragraph::new(lines).block(block), popup);
+}
+
+/// Centered rect of the given size inside `area`, for popups.
+pub(crate) fn popup_area(width: u16, height: u16, area: Rect) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    let x = area.x + (area.width - width) / 2;
+    let y = area.y + (area.height - height) / 2;
+    Rect::new(x, y, width, height)
 }
 
 /// Message log — shows recent game messages.
@@ -96,10 +215,57 @@ pub(crate) fn draw_log(frame: &mut Frame, area: Rect, game: &GameState) {
     let start = game.log.len().saturating_sub(visible);
     let lines: Vec<Line> = game.log[start..]
         .iter()
-        .map(|s| Line::from(Span::styled(s.as_str(), Style::default().fg(Color::DarkGray))))
+        .map(|s| Line::from(markup_spans(s, Style::default().fg(Color::DarkGray))))
         .collect();
 
     let log = Paragraph::new(lines)
         .block(Block::default().title(" Log ").borders(Borders::ALL));
     frame.render_widget(log, area);
 }
+
+pub(crate) fn difficulty_color(difficulty: Difficulty) -> Color {
+    match difficulty {
+        Difficulty::Casual => Color::Green,
+        Difficulty::CorpseRun => Color::Cyan,
+        Difficulty::Normal => Color::Yellow,
+        Difficulty::Hardcore => Color::Red,
+    }
+}
+
+pub(crate) fn rarity_color(rarity: Rarity) -> Color {
+    match rarity {
+        Rarity::Common => Color::White,
+        Rarity::Uncommon => Color::Green,
+        Rarity::Rare => Color::LightBlue,
+        Rarity::Epic => Color::Magenta,
+        Rarity::Legendary => Color::LightYellow,
+    }
+}
+
+/// Split a log line on rarity markup (`{Rare|Bone Shield}`), coloring the
+/// marked names and drawing the rest in `base`.
+pub(crate) fn markup_spans(text: &str, base: Style) -> Vec<Span<'_>> {
+    let mut spans = Vec::new();
+    let mut rest = text;
+    while let Some(open) = rest.find('{') {
+        let marked = rest[open + 1..].split_once('}').and_then(|(inner, after)| {
+            let (label, name) = inner.split_once('|')?;
+            Some((Rarity::from_label(label)?, name, after))
+        });
+        let Some((rarity, name, after)) = marked else {
+            break;
+        };
+        if open > 0 {
+            spans.push(Span::styled(&rest[..open], base));
+        }
+        spans.push(Span::styled(
+            name,
+            base.fg(rarity_color(rarity)).add_modifier(Modifier::BOLD),
+        ));
+        rest = after;
+    }
+    if !rest.is_empty() {
+        spans.push(Span::styled(rest, base));
+    }
+    spans
+}
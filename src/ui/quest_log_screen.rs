@@ -0,0 +1,126 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
+
+use crate::game::quest::QuestStatus;
+use crate::game::GameState;
+
+pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(8),    // quests + details
+            Constraint::Length(1), // hint
+            Constraint::Length(8), // message log
+        ])
+        .split(area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(rows[0]);
+
+    let log = game.quest_log();
+
+    // Left: active quests, then completed ones
+    let items: Vec<ListItem> = log
+        .iter()
+        .enumerate()
+        .map(|(i, (quest, state))| {
+            let done = state.status == QuestStatus::Completed;
+            let ready = !done && quest.is_ready(state, &game.player);
+            let marker = if done {
+                "✔"
+            } else if ready {
+                "!"
+            } else {
+                " "
+            };
+            let style = if i == game.selected {
+                Style::default().fg(Color::Black).bg(Color::Cyan)
+            } else if done {
+                Style::default().fg(Color::DarkGray)
+            } else if ready {
+                Style::default().fg(Color::Green)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            ListItem::new(format!("  {} {}", marker, quest.title)).style(style)
+        })
+        .collect();
+
+    let active = log
+        .iter()
+        .filter(|(_, state)| state.status == QuestStatus::Active)
+        .count();
+    let list = List::new(items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Quests ({} active) ", active)),
+    );
+    frame.render_widget(list, chunks[0]);
+
+    // Right: description, objectives and reward
+    let mut lines = Vec::new();
+    match log.get(game.selected) {
+        Some((quest, state)) => {
+            lines.push(Line::from(Span::styled(
+                quest.title.clone(),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            lines.push(Line::from(""));
+            lines.push(Line::from(quest.description.clone()));
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                "Objectives:",
+                Style::default().fg(Color::Cyan),
+            )));
+            for (i, objective) in quest.objectives.iter().enumerate() {
+                let done = state.status == QuestStatus::Completed
+                    || objective.is_done(state.tracked(i), &game.player);
+                let color = if done { Color::Green } else { Color::White };
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "  {} {}",
+                        if done { "✔" } else { "•" },
+                        objective.describe(state.tracked(i), &game.player)
+                    ),
+                    Style::default().fg(color),
+                )));
+            }
+            let reward = quest.reward.summary();
+            if !reward.is_empty() {
+                lines.push(Line::from(""));
+                lines.push(Line::from(format!("Reward: {}", reward)));
+            }
+            if let Some(ref npc) = quest.turn_in
+                && state.status == QuestStatus::Active
+            {
+                lines.push(Line::from(Span::styled(
+                    format!("Hand in to {}.", npc),
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+        }
+        None => {
+            lines.push(Line::from(Span::styled(
+                "No quests yet. Villagers may have work for you.",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+    }
+
+    let details = Paragraph::new(lines)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL).title(" Details "));
+    frame.render_widget(details, chunks[1]);
+
+    // Hint
+    let hint = Paragraph::new(" ↑↓/jk: select • Esc/l: close")
+        .style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(hint, rows[1]);
+
+    // Message log
+    super::draw_log(frame, rows[2], game);
+}
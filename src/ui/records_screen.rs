@@ -0,0 +1,139 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+
+use crate::game::GameState;
+
+pub fn draw_graveyard(frame: &mut Frame, area: Rect, game: &GameState) {
+    let items: Vec<ListItem> = game
+        .world_state
+        .deaths
+        .iter()
+        .rev()
+        .map(|death| {
+            ListItem::new(vec![
+                Line::from(vec![
+                    Span::styled(
+                        format!("  ✝ {}", death.name),
+                        Style::default()
+                            .fg(super::difficulty_color(death.difficulty))
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!(
+                            "  level {} {} ({})",
+                            death.level,
+                            death.class,
+                            death.difficulty.label()
+                        ),
+                        Style::default().fg(Color::White),
+                    ),
+                ]),
+                Line::from(Span::styled(
+                    format!(
+                        "      slain by the {} at the {}, {}",
+                        death.killer,
+                        death.tile,
+                        death.at.format("%Y-%m-%d")
+                    ),
+                    Style::default().fg(Color::DarkGray),
+                )),
+            ])
+        })
+        .collect();
+
+    draw_records(
+        frame,
+        area,
+        game,
+        " Graveyard ",
+        Color::Red,
+        items,
+        "No hunter has fallen. Yet.",
+    );
+}
+
+pub fn draw_hall_of_fame(frame: &mut Frame, area: Rect, game: &GameState) {
+    let items: Vec<ListItem> = game
+        .world_state
+        .victories
+        .iter()
+        .rev()
+        .map(|victory| {
+            ListItem::new(vec![
+                Line::from(vec![
+                    Span::styled(
+                        format!("  ★ {}", victory.name),
+                        Style::default()
+                            .fg(super::difficulty_color(victory.difficulty))
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!(
+                            "  level {} {} ({})",
+                            victory.level,
+                            victory.class,
+                            victory.difficulty.label()
+                        ),
+                        Style::default().fg(Color::White),
+                    ),
+                ]),
+                Line::from(Span::styled(
+                    format!(
+                        "      brought peace to the land, {}",
+                        victory.at.format("%Y-%m-%d")
+                    ),
+                    Style::default().fg(Color::DarkGray),
+                )),
+            ])
+        })
+        .collect();
+
+    draw_records(
+        frame,
+        area,
+        game,
+        " Hall of Fame ",
+        Color::Yellow,
+        items,
+        "No hunter has vanquished both ancient evils.",
+    );
+}
+
+/// Shared layout: a bordered list of records, newest first, scrolled so
+/// the selected one is on top.
+fn draw_records(
+    frame: &mut Frame,
+    area: Rect,
+    game: &GameState,
+    title: &str,
+    color: Color,
+    items: Vec<ListItem>,
+    empty: &str,
+) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),    // records
+            Constraint::Length(1), // hint
+        ])
+        .split(area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(color))
+        .title(format!("{}({}) ", title, items.len()));
+    if items.is_empty() {
+        let text = Paragraph::new(format!("\n  {}", empty))
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        frame.render_widget(text, rows[0]);
+    } else {
+        let list = List::new(items.into_iter().skip(game.selected).collect::<Vec<_>>())
+            .block(block);
+        frame.render_widget(list, rows[0]);
+    }
+
+    let hint = Paragraph::new(" ↑↓/jk: scroll • Esc: back")
+        .style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(hint, rows[1]);
+}
@@ -0,0 +1,129 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
+
+use crate::game::GameState;
+use crate::game::player::Item;
+use crate::game::shop::ShopTab;
+
+pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
+    let Some(ref shop) = game.shop else {
+        return;
+    };
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(8),    // wares + details
+            Constraint::Length(1), // hint
+            Constraint::Length(8), // message log
+        ])
+        .split(area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(rows[0]);
+
+    // Left: merchant stock or the player's bag, with prices
+    let entries: Vec<(&Item, Option<u32>)> = match shop.tab {
+        ShopTab::Buy => shop
+            .merchant
+            .stock
+            .iter()
+            .map(|item| (item, Some(item.price)))
+            .collect(),
+        ShopTab::Sell => game
+            .player
+            .inventory
+            .iter()
+            .map(|item| (item, shop.sell_price(item)))
+            .collect(),
+    };
+
+    let items: Vec<ListItem> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, (item, price))| {
+            let price = match price {
+                Some(gold) => format!("{:>4} g", gold),
+                None => "     —".into(),
+            };
+            let affordable = shop.tab == ShopTab::Sell || item.price <= game.player.gold;
+            let (style, name_style) = if i == game.selected {
+                (Style::default().fg(Color::Black).bg(Color::Cyan), Style::default())
+            } else if affordable {
+                (
+                    Style::default().fg(Color::White),
+                    Style::default().fg(super::rarity_color(item.rarity)),
+                )
+            } else {
+                (Style::default().fg(Color::DarkGray), Style::default())
+            };
+            ListItem::new(Line::from(vec![
+                Span::raw(format!("  {}  ", price)),
+                Span::styled(item.display_name(), name_style),
+            ]))
+            .style(style)
+        })
+        .collect();
+
+    let tab_style = |tab: ShopTab| {
+        if shop.tab == tab {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        }
+    };
+    let title = Line::from(vec![
+        Span::raw(format!(" {} — ", shop.merchant.name)),
+        Span::styled("Buy", tab_style(ShopTab::Buy)),
+        Span::raw(" | "),
+        Span::styled("Sell", tab_style(ShopTab::Sell)),
+        Span::raw(" "),
+    ]);
+    let block = Block::default().borders(Borders::ALL).title(title);
+
+    if items.is_empty() {
+        let empty = Paragraph::new("  Nothing to trade.")
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        frame.render_widget(empty, chunks[0]);
+    } else {
+        frame.render_widget(List::new(items).block(block), chunks[0]);
+    }
+
+    // Right: details of the highlighted entry
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("Gold: {}", game.player.gold),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    if let Some((item, _)) = entries.get(game.selected) {
+        lines.push(Line::from(Span::styled(
+            item.name.as_str(),
+            Style::default().fg(Color::Cyan),
+        )));
+        lines.extend(item.details().into_iter().map(Line::from));
+    }
+    let details = Paragraph::new(lines)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL).title(" Details "));
+    frame.render_widget(details, chunks[1]);
+
+    // Hint
+    let hint = match shop.tab {
+        ShopTab::Buy => " ↑↓/jk: select • Enter: buy • Tab: sell • Esc: leave",
+        ShopTab::Sell => " ↑↓/jk: select • Enter: sell one • Tab: buy • Esc: leave",
+    };
+    let hint = Paragraph::new(hint).style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(hint, rows[1]);
+
+    // Message log
+    super::draw_log(frame, rows[2], game);
+}
@@ -0,0 +1,98 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+
+use crate::game::leaderboard::format_duration;
+use crate::game::GameState;
+
+pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(8),    // record + kills
+            Constraint::Length(1), // hint
+            Constraint::Length(8), // message log
+        ])
+        .split(area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+        .split(rows[0]);
+
+    // Left: lifetime record
+    let stats = &game.player.stats;
+    let prey = stats
+        .favourite_prey()
+        .map(|(name, count)| format!("{} ({})", name, count))
+        .unwrap_or_else(|| "—".into());
+    let entries = [
+        ("Hunting since", stats.started.format("%Y-%m-%d").to_string()),
+        ("Play time", format_duration(game.play_seconds() as i64)),
+        ("Turns taken", stats.turns.to_string()),
+        (
+            "Tiles visited",
+            format!("{} of {}", stats.visited.len(), game.tiles.len()),
+        ),
+        ("", String::new()),
+        ("Enemies slain", stats.total_kills().to_string()),
+        ("Bosses slain", stats.boss_kills.to_string()),
+        ("Favourite prey", prey),
+        ("Fights fled", stats.fights_fled.to_string()),
+        ("Deaths", stats.deaths.to_string()),
+        ("", String::new()),
+        ("Damage dealt", stats.damage_dealt.to_string()),
+        ("Damage taken", stats.damage_taken.to_string()),
+        ("Biggest hit", stats.biggest_hit.to_string()),
+        ("Potions used", stats.potions_used.to_string()),
+    ];
+    let lines: Vec<Line> = entries
+        .into_iter()
+        .map(|(label, value)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("  {:<16}", label),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(value, Style::default().fg(Color::White)),
+            ])
+        })
+        .collect();
+    let record = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {}'s Record ", game.player.name)),
+    );
+    frame.render_widget(record, chunks[0]);
+
+    // Right: kills by enemy, most first
+    let mut kills: Vec<(&String, &u32)> = stats.kills.iter().collect();
+    kills.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" Kills ({}) ", stats.total_kills()));
+    if kills.is_empty() {
+        let text = Paragraph::new("\n  Nothing slain yet.")
+            .style(Style::default().fg(Color::DarkGray))
+            .block(block);
+        frame.render_widget(text, chunks[1]);
+    } else {
+        let items: Vec<ListItem> = kills
+            .into_iter()
+            .map(|(name, count)| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("  {:>4} × ", count), Style::default().fg(Color::Cyan)),
+                    Span::styled(name.clone(), Style::default().fg(Color::White)),
+                ]))
+            })
+            .collect();
+        frame.render_widget(List::new(items).block(block), chunks[1]);
+    }
+
+    // Hint
+    let hint = Paragraph::new(" Esc/s: close")
+        .style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(hint, rows[1]);
+
+    // Message log
+    super::draw_log(frame, rows[2], game);
+}
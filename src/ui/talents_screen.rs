@@ -0,0 +1,154 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+
+use crate::game::GameState;
+use crate::game::talents::{Attribute, Talent};
+
+pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(8),    // tree + details
+            Constraint::Length(1), // hint
+            Constraint::Length(8), // message log
+        ])
+        .split(area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+        .split(rows[0]);
+
+    let player = &game.player;
+    let highlight = |row: usize| {
+        if row == game.selected {
+            Style::default().fg(Color::Black).bg(Color::Cyan)
+        } else {
+            Style::default()
+        }
+    };
+
+    // Left: attributes, then the talent tree by branch
+    let mut lines = vec![Line::from(Span::styled(
+        format!("  Attributes ({} points)", player.attribute_points),
+        Style::default().fg(Color::Cyan),
+    ))];
+    for (row, attribute) in Attribute::ALL.iter().enumerate() {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "    {:<10} {}",
+                attribute.label(),
+                player.attributes.get(*attribute)
+            ),
+            highlight(row),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!("  Talents ({} points)", player.talent_points),
+        Style::default().fg(Color::Cyan),
+    )));
+    let mut branch = None;
+    for (i, talent) in Talent::ALL.iter().enumerate() {
+        if branch != Some(talent.branch()) {
+            branch = Some(talent.branch());
+            lines.push(Line::from(Span::styled(
+                format!("   {}", talent.branch().label()),
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        let learned = player.talents.contains(talent);
+        let open = talent.requires().is_none_or(|r| player.talents.contains(&r));
+        let (marker, color) = if learned {
+            ("✔", Color::Green)
+        } else if open {
+            ("·", Color::White)
+        } else {
+            ("✕", Color::DarkGray)
+        };
+        let row = Attribute::ALL.len() + i;
+        let style = if row == game.selected {
+            highlight(row)
+        } else {
+            Style::default().fg(color)
+        };
+        lines.push(Line::from(Span::styled(
+            format!("    {} {}", marker, talent.label()),
+            style,
+        )));
+    }
+
+    let tree = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Training "),
+    );
+    frame.render_widget(tree, chunks[0]);
+
+    // Right: what the selection does, and where it leaves the hunter
+    let mut details = Vec::new();
+    match game.selected.checked_sub(Attribute::ALL.len()) {
+        None => {
+            let attribute = Attribute::ALL[game.selected];
+            details.push(Line::from(Span::styled(
+                attribute.label(),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            details.push(Line::from(attribute.description()));
+        }
+        Some(i) => {
+            let talent = Talent::ALL[i];
+            details.push(Line::from(Span::styled(
+                format!("{} ({})", talent.label(), talent.branch().label()),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )));
+            details.push(Line::from(talent.description()));
+            if let Some(required) = talent.requires() {
+                details.push(Line::from(Span::styled(
+                    format!("Requires {}.", required.label()),
+                    Style::default().fg(Color::DarkGray),
+                )));
+            }
+        }
+    }
+    details.push(Line::from(""));
+    details.push(Line::from(Span::styled(
+        "Current:",
+        Style::default().fg(Color::Cyan),
+    )));
+    details.push(Line::from(format!(
+        "  Attack {}  •  Defense {}  •  Max HP {}",
+        player.total_attack(),
+        player.total_defense(),
+        player.total_max_hp()
+    )));
+    details.push(Line::from(format!(
+        "  Accuracy +{}  •  Flee +{}  •  Crit on {}+",
+        player.accuracy(),
+        player.flee_bonus(),
+        player.crit_on()
+    )));
+
+    let details = Paragraph::new(details)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL).title(" Details "));
+    frame.render_widget(details, chunks[1]);
+
+    // Hint
+    let hint = match game.respec_cost {
+        Some(cost) => format!(
+            " ↑↓/jk: select • Enter: spend point • r: respec ({} gold) • Esc: leave",
+            cost * player.level
+        ),
+        None => " ↑↓/jk: select • Enter: spend point • Esc/t: close".into(),
+    };
+    let hint = Paragraph::new(hint).style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(hint, rows[1]);
+
+    // Message log
+    super::draw_log(frame, rows[2], game);
+}
@@ -1,5 +1,5 @@
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
 
 use crate::game::GameState;
 
@@ -12,7 +12,7 @@ const LOGO: &str = r#"
     ╚═╝╩ ╩╩ ╩╚═╝
 "#;
 
-pub fn draw(frame: &mut Frame, area: Rect, _game: &GameState) {
+pub fn draw(frame: &mut Frame, area: Rect, game: &GameState) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -38,10 +38,24 @@ pub fn draw(frame: &mut Frame, area: Rect, _game: &GameState) {
     frame.render_widget(subtitle, chunks[2]);
 
     // Prompt
-    let prompt = Paragraph::new("Press ENTER to start  •  Q to quit")
+    let prompt = Paragraph::new("Press ENTER to start  •  G graveyard  •  H hall of fame  •  B leaderboards  •  Q to quit")
         .style(Style::default().fg(Color::Yellow))
         .alignment(Alignment::Center);
     frame.render_widget(prompt, chunks[3]);
+
+    // Data files that failed to load
+    if !game.notices.is_empty() {
+        let lines: Vec<Line> = game
+            .notices
+            .iter()
+            .map(|notice| Line::from(format!("⚠ {}", notice)))
+            .collect();
+        let notices = Paragraph::new(lines)
+            .style(Style::default().fg(Color::Red))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+        frame.render_widget(notices, chunks[4]);
+    }
 }
 
 pub fn draw_name_entry(frame: &mut Frame, area: Rect, game: &GameState) {
@@ -85,6 +99,118 @@ pub fn draw_name_entry(frame: &mut Frame, area: Rect, game: &GameState) {
     frame.render_widget(hint, chunks[3]);
 }
 
+pub fn draw_class_select(frame: &mut Frame, area: Rect, game: &GameState) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // prompt
+            Constraint::Min(10),   // classes + details
+            Constraint::Length(4), // difficulty
+            Constraint::Length(1), // hint
+        ])
+        .split(area);
+
+    let prompt = Paragraph::new(format!("Choose a path for {}:", game.name_input))
+        .style(Style::default().fg(Color::Yellow))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::BOTTOM));
+    frame.render_widget(prompt, rows[0]);
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+        .split(rows[1]);
+
+    // Left: class list
+    let items: Vec<ListItem> = game
+        .classes
+        .iter()
+        .enumerate()
+        .map(|(i, class)| {
+            let style = if i == game.selected {
+                Style::default().fg(Color::Black).bg(Color::Yellow)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            ListItem::new(format!("  {}", class.name)).style(style)
+        })
+        .collect();
+    let list = List::new(items).block(Block::default().borders(Borders::ALL).title(" Classes "));
+    frame.render_widget(list, chunks[0]);
+
+    // Right: stats, growth, kit and signature ability
+    let mut lines = Vec::new();
+    if let Some(class) = game.classes.get(game.selected) {
+        lines.push(Line::from(Span::styled(
+            class.name.clone(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )));
+        lines.push(Line::from(""));
+        lines.push(Line::from(class.description.clone()));
+        lines.push(Line::from(""));
+        lines.push(Line::from(format!(
+            "HP {}  •  Attack {}  •  Defense {}  •  Stamina {}",
+            class.base.max_hp, class.base.attack, class.base.defense, class.base.stamina
+        )));
+        lines.push(Line::from(format!(
+            "Per level: {}",
+            class.growth.summary()
+        )));
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Starting kit:",
+            Style::default().fg(Color::Cyan),
+        )));
+        for item in class.equipment.items() {
+            lines.push(Line::from(format!(
+                "  {} ({})",
+                item.name,
+                item.stat_summary()
+            )));
+        }
+        for item in &class.items {
+            lines.push(Line::from(format!("  {}", item.display_name())));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("Signature: {}", class.signature.label()),
+            Style::default().fg(Color::Cyan),
+        )));
+        lines.push(Line::from(format!(
+            "  {} ({} ST, {}t cd)",
+            class.signature.description(),
+            class.signature.cost(),
+            class.signature.cooldown()
+        )));
+    }
+    let details = Paragraph::new(lines)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL));
+    frame.render_widget(details, chunks[1]);
+
+    // Difficulty
+    let difficulty = Paragraph::new(vec![
+        Line::from(Span::styled(
+            format!("◂ {} ▸", game.difficulty.label()),
+            Style::default()
+                .fg(super::difficulty_color(game.difficulty))
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(game.difficulty.description()),
+    ])
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true })
+    .block(Block::default().borders(Borders::TOP).title(" Difficulty "));
+    frame.render_widget(difficulty, rows[2]);
+
+    let hint = Paragraph::new("↑↓/jk: class  •  ←→/hl: difficulty  •  ENTER to begin  •  ESC to go back")
+        .style(Style::default().fg(Color::DarkGray))
+        .alignment(Alignment::Center);
+    frame.render_widget(hint, rows[3]);
+}
+
 /// Create a centered rect of given width and height inside `area`.
 fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
     let x = area.x + area.width.saturating_sub(width) / 2;